@@ -1,7 +1,9 @@
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 
-use syn::Expr;
+use syn::{Expr, Member};
+
+use super::structure::msg_args::MsgArgs;
 
 pub struct RespErrorCodeGen {
     pub(crate) ident: syn::Ident,
@@ -29,6 +31,49 @@ impl ToTokens for RespErrorCodeGen {
             )
             .map(|(ident, code)| quote!(Self::#ident{..} => #code));
 
+        let grpc_code_rows = variants
+            .iter()
+            .filter_map(
+                |VariantCodeGen {
+                     ident, grpc_code, ..
+                 }| { Some((ident, grpc_code.as_ref()?)) },
+            )
+            .map(|(ident, code)| quote!(Self::#ident{..} => #code));
+
+        let msg_key_rows = variants
+            .iter()
+            .filter_map(|VariantCodeGen { ident, msg_key, .. }| Some((ident, msg_key.as_ref()?)))
+            .map(|(ident, MsgKeyCodeGen { key, args })| {
+                let bindings = (0..args.len())
+                    .map(|idx| format_ident!("__arg_{idx}"))
+                    .collect::<Vec<_>>();
+                let names = args.iter().map(MsgArgs::name);
+                quote!(Self::#ident{ #(#args: #bindings,)* .. } => ::core::option::Option::Some(
+                    ::axum_resp_result::MessageKey::new(#key)
+                        #(.arg(#names, ::std::string::ToString::to_string(#bindings)))*
+                ))
+            });
+
+        let transparent = variants
+            .iter()
+            .filter_map(|VariantCodeGen { transparent, .. }| transparent.as_ref())
+            .collect::<Vec<_>>();
+
+        // only available with the feature `tonic` of `axum-resp-result`
+        let grpc_code = cfg!(feature = "tonic").then(|| {
+            quote! {
+                fn grpc_code(&self) -> ::axum_resp_result::GrpcCode {
+                    match self {
+                        #(#grpc_code_rows,)*
+                        #(#transparent => ::axum_resp_result::RespError::grpc_code(__inner),)*
+                        _ => ::axum_resp_result::grpc_code_from_http(
+                            ::axum_resp_result::RespError::http_code(self)
+                        )
+                    }
+                }
+            }
+        });
+
         let token = quote! {
             impl ::axum_resp_result::RespError for #ident{
                 fn log_message(&self) -> std::borrow::Cow<'_, str> {
@@ -37,15 +82,31 @@ impl ToTokens for RespErrorCodeGen {
                 fn http_code(&self) -> ::axum_resp_result::StatusCode {
                     match self {
                         #(#http_code_rows,)*
+                        #(#transparent => ::axum_resp_result::RespError::http_code(__inner),)*
                         _=> ::axum_resp_result::StatusCode::INTERNAL_SERVER_ERROR
                     }
                 }
                 fn resp_message(&self) -> std::borrow::Cow<'_, str> {
                     match self{
                         #(#resp_msg_rows,)*
+                        #(#transparent => ::axum_resp_result::RespError::resp_message(__inner),)*
                         _ => <Self as ::axum_resp_result::RespError>::log_message(self)
                     }
                 }
+                fn resp_message_key(&self) -> ::core::option::Option<::axum_resp_result::MessageKey<'_>> {
+                    match self {
+                        #(#msg_key_rows,)*
+                        #(#transparent => ::axum_resp_result::RespError::resp_message_key(__inner),)*
+                        _ => ::core::option::Option::None
+                    }
+                }
+                fn resp_details(&self) -> ::core::option::Option<std::borrow::Cow<'_, [::axum_resp_result::ErrorDetail]>> {
+                    match self {
+                        #(#transparent => ::axum_resp_result::RespError::resp_details(__inner),)*
+                        _ => ::core::option::Option::None
+                    }
+                }
+                #grpc_code
             }
         };
         tokens.extend(token)
@@ -56,4 +117,13 @@ pub struct VariantCodeGen {
     pub(crate) ident: syn::Ident,
     pub(crate) resp_msg: Option<String>,
     pub(crate) http_code: Option<Expr>,
+    pub(crate) grpc_code: Option<Expr>,
+    pub(crate) msg_key: Option<MsgKeyCodeGen>,
+    pub(crate) transparent: Option<TokenStream>,
+}
+
+/// the message key and the fields passing as its arguments
+pub struct MsgKeyCodeGen {
+    pub(crate) key: String,
+    pub(crate) args: Vec<Member>,
 }
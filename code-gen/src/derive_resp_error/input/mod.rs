@@ -1,9 +1,12 @@
 mod variant_info;
 
-use crate::derive_resp_error::codegen::{RespErrorCodeGen, VariantCodeGen};
+use crate::derive_resp_error::codegen::{MsgKeyCodeGen, RespErrorCodeGen, VariantCodeGen};
 use crate::derive_resp_error::input::variant_info::VariantInfo;
+use crate::derive_resp_error::structure::msg_args::MsgArgs;
 use darling::util::Ignored;
 use darling::{ast, FromDeriveInput};
+use quote::quote;
+use syn::spanned::Spanned;
 
 #[derive(Debug, FromDeriveInput)]
 #[darling(attributes(resp_result), supports(enum_any))]
@@ -26,14 +29,93 @@ impl TryInto<RespErrorCodeGen> for RespErrorDeriveInput {
         for VariantInfo {
             ident,
             http_code,
+            grpc_code,
             resp_msg,
+            msg_key,
+            args,
+            transparent,
+            fields,
         } in variants
         {
+            let transparent = if transparent {
+                if resp_msg.is_some()
+                    || http_code.is_some()
+                    || grpc_code.is_some()
+                    || msg_key.is_some()
+                    || args.is_some()
+                {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "`transparent` can not using with `err_msg`, `err_code`, `grpc_code`, `msg_key` or `args`",
+                    ));
+                }
+                let field = match fields.fields.as_slice() {
+                    [field] => field,
+                    _ => {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            "`transparent` variant must have exactly one field",
+                        ))
+                    }
+                };
+                let pattern = match &field.ident {
+                    Some(name) => quote!(Self::#ident{ #name: __inner }),
+                    None => quote!(Self::#ident(__inner)),
+                };
+                Some(pattern)
+            } else {
+                None
+            };
+            // the `grpc_code` is only available with the feature `tonic` of `axum-resp-result`
+            if let Some(grpc_code) = grpc_code.as_ref().filter(|_| !cfg!(feature = "tonic")) {
+                return Err(syn::Error::new(
+                    grpc_code.span(),
+                    "`grpc_code` is only available with the feature `tonic` of `axum-resp-result`",
+                ));
+            }
+            let msg_key = match (msg_key, args) {
+                (Some(key), args) => {
+                    let args = args.map(|args| args.0).unwrap_or_default();
+                    // the argument must be a field of this variant
+                    for member in &args {
+                        let exist = match member {
+                            syn::Member::Named(name) => fields
+                                .fields
+                                .iter()
+                                .any(|field| field.ident.as_ref() == Some(name)),
+                            syn::Member::Unnamed(index) => {
+                                fields.style.is_tuple() && (index.index as usize) < fields.len()
+                            }
+                        };
+                        if !exist {
+                            return Err(syn::Error::new(
+                                member.span(),
+                                format!(
+                                    "`{}` is not a field of variant `{ident}`",
+                                    MsgArgs::name(member)
+                                ),
+                            ));
+                        }
+                    }
+                    Some(MsgKeyCodeGen { key, args })
+                }
+                (None, Some(_)) => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "`args` can only using with `msg_key`",
+                    ))
+                }
+                (None, None) => None,
+            };
             let http_code = http_code.map(TryInto::try_into).transpose()?;
+            let grpc_code = grpc_code.map(TryInto::try_into).transpose()?;
             vars.push(VariantCodeGen {
                 ident,
                 resp_msg,
                 http_code,
+                grpc_code,
+                msg_key,
+                transparent,
             })
         }
 
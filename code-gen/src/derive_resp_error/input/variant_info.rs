@@ -1,5 +1,7 @@
-use crate::derive_resp_error::structure::http_code::HttpCode;
-use darling::FromVariant;
+use crate::derive_resp_error::structure::{
+    grpc_code::GrpcCode, http_code::HttpCode, msg_args::MsgArgs,
+};
+use darling::{ast, FromField, FromVariant};
 
 #[derive(Debug, FromVariant)]
 #[darling(attributes(resp_result))]
@@ -9,4 +11,15 @@ pub struct VariantInfo {
     pub(crate) resp_msg: Option<String>,
     #[darling(rename = "err_code")]
     pub(crate) http_code: Option<HttpCode>,
+    pub(crate) grpc_code: Option<GrpcCode>,
+    pub(crate) msg_key: Option<String>,
+    pub(crate) args: Option<MsgArgs>,
+    #[darling(default)]
+    pub(crate) transparent: bool,
+    pub(crate) fields: ast::Fields<FieldInfo>,
+}
+
+#[derive(Debug, FromField)]
+pub struct FieldInfo {
+    pub(crate) ident: Option<syn::Ident>,
 }
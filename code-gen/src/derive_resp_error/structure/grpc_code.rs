@@ -0,0 +1,110 @@
+use darling::FromMeta;
+use heck::ToUpperCamelCase;
+use proc_macro2::{Ident, Span};
+use quote::quote;
+use syn::{parse2, Expr, Lit};
+
+/// the names of gRPC code, the index is the value of the code
+const CODE_NAMES: [&str; 17] = [
+    "Ok",
+    "Cancelled",
+    "Unknown",
+    "InvalidArgument",
+    "DeadlineExceeded",
+    "NotFound",
+    "AlreadyExists",
+    "PermissionDenied",
+    "ResourceExhausted",
+    "FailedPrecondition",
+    "Aborted",
+    "OutOfRange",
+    "Unimplemented",
+    "Internal",
+    "Unavailable",
+    "DataLoss",
+    "Unauthenticated",
+];
+
+#[derive(Debug)]
+pub enum GrpcCode {
+    Str(String, Span),
+    Num(u16, Span),
+}
+
+impl GrpcCode {
+    pub fn span(&self) -> Span {
+        match self {
+            GrpcCode::Str(_, span) | GrpcCode::Num(_, span) => *span,
+        }
+    }
+}
+
+impl FromMeta for GrpcCode {
+    fn from_value(lit: &Lit) -> darling::Result<Self> {
+        let span = lit.span();
+        match lit {
+            Lit::Int(lit_int) => {
+                let num = lit_int.base10_parse::<u16>()?;
+                Ok(Self::Num(num, span))
+            }
+            Lit::Str(str) => Ok(Self::Str(str.value(), span)),
+            _ => Err(syn::Error::new(span, "Unexpected literal type").into()),
+        }
+    }
+}
+
+impl TryInto<Expr> for GrpcCode {
+    type Error = syn::Error;
+
+    fn try_into(self) -> Result<Expr, Self::Error> {
+        let (name, span) = match self {
+            GrpcCode::Str(str, span) => {
+                let name = str.to_upper_camel_case();
+                let name = CODE_NAMES
+                    .into_iter()
+                    .find(|code| *code == name)
+                    .ok_or_else(|| syn::Error::new(span, format!("Unknown gRPC Code `{str}`")))?;
+                (name, span)
+            }
+            GrpcCode::Num(code, span) => {
+                let name = CODE_NAMES
+                    .get(code as usize)
+                    .ok_or_else(|| syn::Error::new(span, format!("Unknown gRPC Code `{code}`")))?;
+                (*name, span)
+            }
+        };
+        let name = Ident::new(name, span);
+        parse2(quote!(::axum_resp_result::GrpcCode::#name))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use darling::FromMeta;
+    use quote::ToTokens;
+    use syn::{parse_quote, Expr, Lit};
+
+    use super::GrpcCode;
+
+    fn parse(lit: Lit) -> syn::Result<Expr> {
+        GrpcCode::from_value(&lit).unwrap().try_into()
+    }
+
+    #[test]
+    fn test_parse_code() {
+        let expected = ":: axum_resp_result :: GrpcCode :: NotFound";
+        for lit in [
+            parse_quote!(5),
+            parse_quote!("NotFound"),
+            parse_quote!("NOT_FOUND"),
+        ] {
+            assert_eq!(parse(lit).unwrap().to_token_stream().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_no_exist_code() {
+        assert!(parse(parse_quote!(17)).is_err());
+        assert!(parse(parse_quote!("NotExist")).is_err());
+    }
+}
@@ -1 +1,3 @@
-pub(super) mod http_code;
+pub(crate) mod grpc_code;
+pub(crate) mod http_code;
+pub(crate) mod msg_args;
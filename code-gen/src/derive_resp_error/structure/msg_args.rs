@@ -0,0 +1,69 @@
+use darling::{ast::NestedMeta, FromMeta};
+use syn::{spanned::Spanned, Index, Lit, Member, Meta};
+
+/// the fields passing to the `MessageKey` as arguments,
+/// like `args(id, name)` for named fields or `args(0)` for unnamed fields
+#[derive(Debug, Default)]
+pub struct MsgArgs(pub(crate) Vec<Member>);
+
+impl MsgArgs {
+    /// the name of the argument, which is the name or index of the field
+    pub fn name(member: &Member) -> String {
+        match member {
+            Member::Named(ident) => ident.to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        }
+    }
+}
+
+impl FromMeta for MsgArgs {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| match item {
+                NestedMeta::Meta(Meta::Path(path)) => path
+                    .get_ident()
+                    .map(|ident| Member::Named(ident.clone()))
+                    .ok_or_else(|| darling::Error::custom("Expect a field name").with_span(path)),
+                NestedMeta::Lit(Lit::Int(lit)) => Ok(Member::Unnamed(Index {
+                    index: lit.base10_parse()?,
+                    span: lit.span(),
+                })),
+                _ => Err(
+                    darling::Error::custom("Expect a field name or a field index")
+                        .with_span(&item.span()),
+                ),
+            })
+            .collect::<darling::Result<_>>()
+            .map(Self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use darling::FromMeta;
+    use syn::{parse_quote, Member, Meta};
+
+    use super::MsgArgs;
+
+    fn parse(meta: Meta) -> darling::Result<Vec<String>> {
+        MsgArgs::from_meta(&meta).map(|args| args.0.iter().map(MsgArgs::name).collect())
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse(parse_quote!(args(id, name))).unwrap(), ["id", "name"]);
+        assert_eq!(parse(parse_quote!(args(0, 1))).unwrap(), ["0", "1"]);
+        assert!(matches!(
+            MsgArgs::from_meta(&parse_quote!(args(1))).unwrap().0[0],
+            Member::Unnamed(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse_bad_args() {
+        assert!(parse(parse_quote!(args("id"))).is_err());
+        assert!(parse(parse_quote!(args(a::b))).is_err());
+        assert!(parse(parse_quote!(args(id = 1))).is_err());
+    }
+}
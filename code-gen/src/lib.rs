@@ -5,22 +5,37 @@ use syn::spanned::Spanned;
 use syn::{parse_macro_input, DeriveInput};
 
 use crate::derive_resp_error::gen_resp_error_derive;
-use proc_resp_result::Function;
+use proc_resp_result::{Function, ImplBlock, RespResultArgs};
 
 /// convert a return [Result] [`Handler`](axum::Handler) return [`RespResult`]
+///
+/// - on a function or a method, convert it
+/// - on an inherent `impl` block, convert every method returning `Result` or `RespResult`
+///
+/// the arguments `status`, `header`, `append_header`, `remove_header`, `empty_body` and `no_content`
+/// will wrap the success value with [`FlagWrap`] with corresponding [`ExtraFlags`]
 #[proc_macro_attribute]
 pub fn resp_result(
-    _: proc_macro::TokenStream,
+    args: proc_macro::TokenStream,
     input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
-    let func = syn::parse_macro_input!(input as Function);
-    quote!(#func).into()
+    let args = syn::parse_macro_input!(args as RespResultArgs);
+    let item = syn::parse_macro_input!(input as syn::Item);
+    let output = match item {
+        syn::Item::Impl(item) => ImplBlock::new(item, &args).map(|block| quote!(#block)),
+        syn::Item::Fn(item) => Function::from_item_fn(item, &args).map(|func| quote!(#func)),
+        item => Err(syn::Error::new(
+            item.span(),
+            "macro `resp-result` only support function, method or `impl` block",
+        )),
+    };
+    output.unwrap_or_else(|err| err.into_compile_error()).into()
 }
 
 #[proc_macro_derive(RespError, attributes(resp_result))]
 pub fn derive_resp_error(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    if cfg!(feature = "extra-err") {
+    if cfg!(feature = "extra-error") {
         return syn::Error::new(
             input.span(),
             "Derive macro `RespError` not support `extra-message` yet",
@@ -0,0 +1,208 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Expr, Ident, LitStr, Token, Type,
+};
+
+use crate::derive_resp_error::structure::http_code::HttpCode;
+
+/// the arguments of `resp_result` attribute macro
+///
+/// ```rust ignore
+/// #[resp_result(
+///     status = 201,
+///     header("Cache-Control" = "no-store"),
+///     append_header("Set-Cookie" = "a=1", "Set-Cookie" = "b=2"),
+///     remove_header("Content-Type"),
+///     empty_body,
+///     empty_body_on = Nil,
+///     no_content,
+///     none = MyError::NotFound
+/// )]
+/// ```
+#[derive(Default)]
+pub struct RespResultArgs {
+    flags: Vec<FlagArg>,
+    none: Option<Expr>,
+}
+
+enum Arg {
+    Flags(Vec<FlagArg>),
+    None(Expr),
+}
+
+enum FlagArg {
+    Status(Expr),
+    Header(LitStr, LitStr),
+    AppendHeader(LitStr, LitStr),
+    RemoveHeader(LitStr),
+    EmptyBody,
+    EmptyBodyOn(Type),
+    NoContent,
+}
+
+impl RespResultArgs {
+    /// the expression of [`ExtraFlags`](axum_resp_result::ExtraFlags) if any flags provided,
+    /// the `success` is the type of success body, using by the conditional flags
+    pub fn flags(&self, success: &TokenStream) -> Option<TokenStream> {
+        (!self.flags.is_empty()).then(|| {
+            let flags = self.flags.iter().map(|flag| flag.to_flag(success));
+            quote!(::axum_resp_result::ExtraFlags::from(()) #(+ #flags)*)
+        })
+    }
+
+    /// the expression of the error when the success value is `None`, if `none` provided
+    pub fn none(&self) -> Option<&Expr> {
+        self.none.as_ref()
+    }
+}
+
+impl Parse for RespResultArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = Self::default();
+        for arg in Punctuated::<_, Token![,]>::parse_terminated_with(input, parse_arg)? {
+            match arg {
+                Arg::Flags(flags) => args.flags.extend(flags),
+                Arg::None(expr) => args.none = Some(expr),
+            }
+        }
+        Ok(args)
+    }
+}
+
+fn parse_arg(input: ParseStream) -> syn::Result<Arg> {
+    let ident = input.parse::<Ident>()?;
+    if ident == "none" {
+        // `none` without value using the prefab `NotFound`
+        let expr = match input.parse::<Option<Token![=]>>()? {
+            Some(_) => input.parse::<Expr>()?,
+            None => syn::parse_quote!(::axum_resp_result::NotFound),
+        };
+        return Ok(Arg::None(expr));
+    }
+    let flags = match ident.to_string().as_str() {
+        "status" => {
+            input.parse::<Token![=]>()?;
+            let code = input.parse::<HttpCode>()?;
+            vec![FlagArg::Status(code.try_into()?)]
+        }
+        "header" | "append_header" => {
+            let content;
+            parenthesized!(content in input);
+            Punctuated::<(LitStr, LitStr), Token![,]>::parse_terminated_with(
+                &content,
+                parse_header_pair,
+            )?
+            .into_iter()
+            .map(|(name, value)| match ident == "header" {
+                true => FlagArg::Header(name, value),
+                false => FlagArg::AppendHeader(name, value),
+            })
+            .collect()
+        }
+        "remove_header" => {
+            let content;
+            parenthesized!(content in input);
+            Punctuated::<LitStr, Token![,]>::parse_terminated_with(&content, parse_header_name)?
+                .into_iter()
+                .map(FlagArg::RemoveHeader)
+                .collect()
+        }
+        "empty_body" => vec![FlagArg::EmptyBody],
+        "empty_body_on" => {
+            input.parse::<Token![=]>()?;
+            vec![FlagArg::EmptyBodyOn(input.parse()?)]
+        }
+        "no_content" => vec![FlagArg::NoContent],
+        _ => {
+            return Err(syn::Error::new(
+                ident.span(),
+                "Unknown argument, expect one of `status`, `header`, `append_header`, `remove_header`, `empty_body`, `empty_body_on`, `no_content` or `none`",
+            ))
+        }
+    };
+    Ok(Arg::Flags(flags))
+}
+
+fn parse_header_name(input: ParseStream) -> syn::Result<LitStr> {
+    let name = input.parse::<LitStr>()?;
+    http::HeaderName::from_bytes(name.value().as_bytes())
+        .map_err(|err| syn::Error::new(name.span(), err))?;
+    Ok(name)
+}
+
+fn parse_header_pair(input: ParseStream) -> syn::Result<(LitStr, LitStr)> {
+    let name = parse_header_name(input)?;
+    input.parse::<Token![=]>()?;
+    let value = input.parse::<LitStr>()?;
+    http::HeaderValue::from_str(&value.value())
+        .map_err(|err| syn::Error::new(value.span(), err))?;
+    Ok((name, value))
+}
+
+impl FlagArg {
+    /// the expression of [`ExtraFlag`](axum_resp_result::ExtraFlag) or [`ExtraFlags`](axum_resp_result::ExtraFlags)
+    fn to_flag(&self, success: &TokenStream) -> TokenStream {
+        match self {
+            FlagArg::Status(status) => quote!(::axum_resp_result::ExtraFlag::status(#status)),
+            FlagArg::Header(name, value) => {
+                quote!(::axum_resp_result::ExtraFlag::insert_header(#name, #value))
+            }
+            FlagArg::AppendHeader(name, value) => {
+                quote!(::axum_resp_result::ExtraFlag::append_header(#name, #value))
+            }
+            FlagArg::RemoveHeader(name) => {
+                quote!(::axum_resp_result::ExtraFlag::remove_header(#name))
+            }
+            FlagArg::EmptyBody => quote!(::axum_resp_result::ExtraFlag::empty_body()),
+            FlagArg::EmptyBodyOn(ty) => {
+                quote!(::axum_resp_result::ExtraFlags::empty_body_on::<#ty, #success>())
+            }
+            FlagArg::NoContent => quote!(::axum_resp_result::ExtraFlag::no_content()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use quote::quote;
+
+    use super::RespResultArgs;
+
+    #[test]
+    fn test_parse_args() {
+        let args = syn::parse_str::<RespResultArgs>(
+            r#"status = 201, header("Cache-Control" = "no-store", "X-Foo" = "bar"), remove_header("Etag"), empty_body"#,
+        )
+        .unwrap();
+        assert_eq!(args.flags.len(), 5);
+
+        let args = syn::parse_str::<RespResultArgs>("").unwrap();
+        assert!(args.flags(&quote!(i32)).is_none());
+        assert!(args.none().is_none());
+
+        let args = syn::parse_str::<RespResultArgs>("none").unwrap();
+        assert!(args.none().is_some());
+
+        let args =
+            syn::parse_str::<RespResultArgs>("none = MyError::NotFound, status = 200").unwrap();
+        assert!(args.none().is_some());
+        assert!(args.flags(&quote!(i32)).is_some());
+
+        let args = syn::parse_str::<RespResultArgs>("empty_body_on = Nil").unwrap();
+        let flags = args.flags(&quote!(i32)).unwrap().to_string();
+        assert!(flags.contains("ExtraFlags :: empty_body_on :: < Nil , i32 > ()"));
+    }
+
+    #[test]
+    fn test_parse_bad_args() {
+        assert!(syn::parse_str::<RespResultArgs>("status = 9101").is_err());
+        assert!(syn::parse_str::<RespResultArgs>(r#"header("Bad Name" = "v")"#).is_err());
+        assert!(syn::parse_str::<RespResultArgs>(r#"header("X-Foo" = "bad\nvalue")"#).is_err());
+        assert!(syn::parse_str::<RespResultArgs>("unknown").is_err());
+        assert!(syn::parse_str::<RespResultArgs>("empty_body_on").is_err());
+    }
+}
@@ -1,18 +1,38 @@
+use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use syn::{
-    parse::Parse, spanned::Spanned, Attribute, Block, ItemFn, Pat, PatIdent, ReturnType, Signature,
-    Visibility,
+    spanned::Spanned, Attribute, Block, Expr, FnArg, ImplItemFn, ItemFn, Pat, PatIdent, Path,
+    ReturnType, Signature, Type, Visibility,
 };
 
+use super::{args::RespResultArgs, try_rewrite::TryRewriter};
+
+/// the attributes copied onto the hidden sibling method
+const SIBLING_ATTRS: [&str; 8] = [
+    "cfg",
+    "cfg_attr",
+    "allow",
+    "warn",
+    "deny",
+    "forbid",
+    "expect",
+    "track_caller",
+];
+
 pub struct Function {
     attrs: Vec<Attribute>,
     vis: Visibility,
     inner_sig: Signature,
     outer_sig: Signature,
+    ret_type: TokenStream,
     block: Box<Block>,
     args: Vec<syn::Ident>,
+    bindings: Vec<TokenStream>,
     inner_ident: syn::Ident,
     is_async: bool,
+    is_method: bool,
+    flags: Option<TokenStream>,
+    to_result: TokenStream,
 }
 
 impl ToTokens for Function {
@@ -21,11 +41,16 @@ impl ToTokens for Function {
             attrs,
             vis,
             inner_sig,
+            ret_type,
             block,
             outer_sig,
             args,
+            bindings,
             inner_ident,
             is_async,
+            is_method,
+            flags,
+            to_result,
         } = self;
 
         let ay = if *is_async {
@@ -33,16 +58,47 @@ impl ToTokens for Function {
         } else {
             None
         };
-        let inner = quote!(#inner_sig #block);
+
+        // the method in `impl` block will be a hidden sibling method,
+        // otherwise the body will be inlined as a closure (or an async block),
+        // since the nested function can not using the `Self` and generic parameters of the outer `impl`
+        let (sibling, call) = if *is_method {
+            let receiver = outer_sig.receiver().map(|_| quote!(self,));
+            let sibling_attrs = attrs.iter().filter(|attr| {
+                attr.path()
+                    .get_ident()
+                    .is_some_and(|ident| SIBLING_ATTRS.iter().any(|name| ident == name))
+            });
+            (
+                Some(quote!(#(#sibling_attrs)* #[doc(hidden)] #inner_sig #block)),
+                quote!(Self::#inner_ident(#receiver #(#args),*) #ay),
+            )
+        } else {
+            let body = quote!({ #(#bindings)* #block });
+            let call = if *is_async {
+                quote!(::axum_resp_result::__async_body::<#ret_type, _>(async move #body).await)
+            } else {
+                quote!((move || -> #ret_type #body)())
+            };
+            (None, call)
+        };
+
+        let ret = match flags {
+            Some(flags) => quote!(::axum_resp_result::RespResult::with_flags(__tmp, #flags)),
+            None => quote!(__tmp),
+        };
+
         let outer = quote! {
+            #sibling
+
             #(#attrs)*
             #vis #outer_sig
             {
-                #inner
-
-                let __tmp = #inner_ident(#(#args),*)#ay;
-                let __tmp = ::axum_resp_result::Fallible::to_result(__tmp);
-                ::axum_resp_result::IntoRespResult::into_rresult(__tmp)
+                #[allow(clippy::redundant_closure_call)]
+                let __tmp = #call;
+                let __tmp = #to_result;
+                let __tmp = ::axum_resp_result::IntoRespResult::into_rresult(__tmp);
+                #ret
             }
         };
 
@@ -50,37 +106,90 @@ impl ToTokens for Function {
     }
 }
 
-impl Parse for Function {
-    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let ItemFn {
+impl Function {
+    /// load the function, it will be treated as a method if it has receiver
+    pub fn from_item_fn(item: ItemFn, args: &RespResultArgs) -> syn::Result<Self> {
+        let is_method = item.sig.receiver().is_some();
+        Self::new(item, is_method, args)
+    }
+
+    /// load the method in `impl` block, it will always be treated as a method
+    pub fn from_impl_fn(
+        ImplItemFn {
             attrs,
             vis,
             sig,
             block,
-        } = input.parse::<ItemFn>()?;
+            ..
+        }: ImplItemFn,
+        args: &RespResultArgs,
+    ) -> syn::Result<Self> {
+        let item = ItemFn {
+            attrs,
+            vis,
+            sig,
+            block: Box::new(block),
+        };
+        Self::new(item, true, args)
+    }
 
+    fn new(
+        ItemFn {
+            attrs,
+            vis,
+            sig,
+            mut block,
+        }: ItemFn,
+        is_method: bool,
+        args: &RespResultArgs,
+    ) -> syn::Result<Self> {
         let mut inner_sig = sig;
-        if inner_sig.receiver().is_some() {
-            return Err(syn::Error::new(
-                inner_sig.receiver().span(),
-                "macro `resp-result` not support for method",
-            ));
+
+        if TryRewriter::need_rewrite(&inner_sig.output) {
+            TryRewriter::rewrite(&mut block);
         }
 
         let mut outer_sig = inner_sig.clone();
 
         // set return type
         let ret = outer_sig.output;
-        let ret_type = match ret {
+        let ret_type = match &ret {
             syn::ReturnType::Default => quote!(()),
             syn::ReturnType::Type(_, ty) => quote!(#ty),
         };
 
+        // the `Option` has no failure type, it comes from the `none` expression instead
+        let none_of_option = match (&ret, args.none()) {
+            (ReturnType::Type(_, ty), Some(none)) if is_option(ty) => {
+                Some((none, failure_of_none(none)?))
+            }
+            _ => None,
+        };
+        let (fallible, failure, to_result) = match (none_of_option, args.none()) {
+            (Some((none, failure)), _) => (
+                quote!(::axum_resp_result::Fallible),
+                quote!(#failure),
+                quote!(::core::option::Option::ok_or_else::<#failure, _>(__tmp, || #none)),
+            ),
+            (None, Some(none)) => (
+                quote!(::axum_resp_result::FallibleOption),
+                quote!(<#ret_type as ::axum_resp_result::FallibleOption>::Failure),
+                quote!(::axum_resp_result::FallibleOption::to_result_or(__tmp, || #none)),
+            ),
+            (None, None) => (
+                quote!(::axum_resp_result::Fallible),
+                quote!(<#ret_type as ::axum_resp_result::Fallible>::Failure),
+                quote!(::axum_resp_result::Fallible::to_result(__tmp)),
+            ),
+        };
+        let success = quote!(<#ret_type as #fallible>::Success);
+        let flags = args.flags(&success);
+        let success = match flags {
+            Some(_) => quote!(::axum_resp_result::FlagWrap<#success>),
+            None => success,
+        };
         let new_ret = quote! {
-            ::axum_resp_result::RespResult<
-                <#ret_type as ::axum_resp_result::Fallible>::Success,
-                <#ret_type as ::axum_resp_result::Fallible>::Failure,
-            >
+            ::axum_resp_result::RespResult<#success, #failure>
         };
 
         let new_ret = syn::parse::<ReturnType>(quote!(-> #new_ret).into())?;
@@ -88,17 +197,22 @@ impl Parse for Function {
         outer_sig.output = new_ret;
 
         let mut args = Vec::new();
+        let mut bindings = Vec::new();
         // set arg lists
-        for (idx, ty) in outer_sig
-            .inputs
-            .iter_mut()
-            .map(|v| match v {
-                syn::FnArg::Typed(ty) => ty,
-                syn::FnArg::Receiver(_) => unreachable!(),
-            })
-            .enumerate()
-        {
+        for (idx, arg) in outer_sig.inputs.iter_mut().enumerate() {
+            let ty = match arg {
+                FnArg::Typed(ty) => ty,
+                FnArg::Receiver(receiver) => {
+                    // the `self` only moving into inner method
+                    if receiver.reference.is_none() {
+                        receiver.mutability = None;
+                    }
+                    continue;
+                }
+            };
             let ident = format_ident!("arg_{idx}");
+            let (pat, arg_ty) = (&ty.pat, &ty.ty);
+            bindings.push(quote!(let #pat: #arg_ty = #ident;));
             args.push(ident.clone());
             let pat = Pat::Ident(PatIdent {
                 attrs: vec![],
@@ -108,20 +222,104 @@ impl Parse for Function {
                 subpat: None,
             });
 
-            ty.pat = Box::new(pat)
+            *ty.pat = pat
         }
         // set inner ident
-        let inner_ident = format_ident!("__inner_func");
+        let inner_ident = format_ident!("__resp_result_{}", inner_sig.ident);
         inner_sig.ident = inner_ident.clone();
         Ok(Self {
             attrs,
             vis,
             inner_sig,
+            ret_type,
             block,
             outer_sig,
             args,
+            bindings,
             inner_ident,
             is_async,
+            is_method,
+            flags,
+            to_result,
         })
     }
 }
+
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|seg| seg.ident == "Option"),
+        Type::Paren(paren) => is_option(&paren.elem),
+        Type::Group(group) => is_option(&group.elem),
+        _ => false,
+    }
+}
+
+/// the failure type of `none` expression on the function returning `Option`,
+/// it is the enum of the variant (`MyError::NotFound`, `MyError::NotFound(0)`),
+/// the type of the associated function (`MyError::new(0)`) or the unit struct (`NotFound`)
+fn failure_of_none(none: &Expr) -> syn::Result<Path> {
+    let path = match none {
+        Expr::Path(expr) if expr.qself.is_none() => &expr.path,
+        Expr::Call(call) => match &*call.func {
+            Expr::Path(expr) if expr.qself.is_none() => &expr.path,
+            _ => return Err(none_error(none)),
+        },
+        Expr::Struct(expr) if expr.qself.is_none() => &expr.path,
+        _ => return Err(none_error(none)),
+    };
+    let is_type = |idx: usize| {
+        path.segments[idx]
+            .ident
+            .to_string()
+            .starts_with(|c: char| c.is_ascii_uppercase())
+    };
+    let len = path.segments.len();
+    let mut path = path.clone();
+    if len > 1 && is_type(len - 2) {
+        // drop the variant (or the associated function)
+        path.segments.pop();
+        path.segments.pop_punct();
+    } else if !is_type(len - 1) {
+        return Err(none_error(none));
+    }
+    Ok(path)
+}
+
+fn none_error(none: &Expr) -> syn::Error {
+    syn::Error::new(
+        none.span(),
+        "the `none` of function returning `Option` must be a variant of the error enum or a unit struct, like `MyError::NotFound`, `MyError::NotFound(0)` or `NotFound`",
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use quote::quote;
+
+    use super::failure_of_none;
+
+    #[test]
+    fn test_failure_of_none() {
+        let failure = |expr: &str| {
+            failure_of_none(&syn::parse_str(expr).unwrap()).map(|path| quote!(#path).to_string())
+        };
+        assert_eq!(failure("MyError::Missing").unwrap(), "MyError");
+        assert_eq!(failure("MyError::NotFound(0)").unwrap(), "MyError");
+        assert_eq!(
+            failure("crate::error::MyError::Bad { id: 1 }").unwrap(),
+            "crate :: error :: MyError"
+        );
+        assert_eq!(failure("NotFound").unwrap(), "NotFound");
+        assert_eq!(
+            failure("::axum_resp_result::NotFound").unwrap(),
+            ":: axum_resp_result :: NotFound"
+        );
+        assert_eq!(failure("MyError::new(1)").unwrap(), "MyError");
+        assert!(failure("missing()").is_err());
+        assert!(failure("if a { b } else { c }").is_err());
+    }
+}
@@ -0,0 +1,115 @@
+use quote::{quote, ToTokens};
+use syn::{spanned::Spanned, ImplItem, ImplItemFn, ItemImpl, ReturnType, Type};
+
+use super::{Function, RespResultArgs};
+
+/// the `impl` block with every `Result` returning method converted
+pub struct ImplBlock {
+    item: ItemImpl,
+    functions: Vec<Function>,
+}
+
+impl ImplBlock {
+    /// whether the method need to be converted
+    /// - the method return `Result` or `RespResult`
+    /// - the method not marked by `resp_result` itself
+    fn need_convert(func: &ImplItemFn) -> bool {
+        let marked = func.attrs.iter().any(|attr| {
+            attr.path()
+                .segments
+                .last()
+                .is_some_and(|seg| seg.ident == "resp_result" || seg.ident == "rresult")
+        });
+        let ReturnType::Type(_, ty) = &func.sig.output else {
+            return false;
+        };
+        let returning = match &**ty {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .is_some_and(|seg| seg.ident == "Result" || seg.ident == "RespResult"),
+            _ => false,
+        };
+        !marked && returning
+    }
+}
+
+impl ImplBlock {
+    /// load the `impl` block, the `args` will apply on every converted method
+    pub fn new(mut item: ItemImpl, args: &RespResultArgs) -> syn::Result<Self> {
+        if let Some((_, path, _)) = &item.trait_ {
+            return Err(syn::Error::new(
+                path.span(),
+                "macro `resp-result` only support inherent `impl` block",
+            ));
+        }
+
+        let mut functions = Vec::new();
+        let mut items = Vec::with_capacity(item.items.len());
+        for impl_item in item.items {
+            match impl_item {
+                ImplItem::Fn(func) if Self::need_convert(&func) => {
+                    functions.push(Function::from_impl_fn(func, args)?)
+                }
+                impl_item => items.push(impl_item),
+            }
+        }
+        item.items = items;
+
+        Ok(Self { item, functions })
+    }
+}
+
+impl ToTokens for ImplBlock {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let ImplBlock { item, functions } = self;
+        let ItemImpl {
+            attrs,
+            defaultness,
+            unsafety,
+            impl_token,
+            generics,
+            self_ty,
+            items,
+            ..
+        } = item;
+        let where_clause = &generics.where_clause;
+
+        tokens.extend(quote! {
+            #(#attrs)*
+            #defaultness #unsafety #impl_token #generics #self_ty #where_clause {
+                #(#items)*
+                #(#functions)*
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use syn::ImplItemFn;
+
+    use super::ImplBlock;
+
+    #[test]
+    fn test_need_convert() {
+        let need =
+            |func: &str| ImplBlock::need_convert(&syn::parse_str::<ImplItemFn>(func).unwrap());
+
+        assert!(need("fn a(&self) -> Result<u32, E> { todo!() }"));
+        assert!(need(
+            "async fn a(self: Arc<Self>) -> RespResult<u32, E> { todo!() }"
+        ));
+        assert!(!need("fn a(&self) -> u32 { todo!() }"));
+        assert!(!need(
+            "#[rresult] fn a(&self) -> Result<u32, E> { todo!() }"
+        ));
+    }
+
+    #[test]
+    fn test_reject_trait_impl() {
+        let item = syn::parse_str("impl Foo for Bar {}").unwrap();
+        assert!(ImplBlock::new(item, &Default::default()).is_err());
+    }
+}
@@ -1,3 +1,8 @@
+mod args;
 mod function_loader;
+mod impl_loader;
+mod try_rewrite;
 
+pub use args::RespResultArgs;
 pub use function_loader::Function;
+pub use impl_loader::ImplBlock;
@@ -0,0 +1,211 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::ParseStream, parse_quote_spanned, punctuated::Punctuated, spanned::Spanned,
+    visit_mut::VisitMut, Block, Expr, ExprTry, Item, Macro, ReturnType, Token, Type,
+};
+
+/// rewrite the `?` operator in the function body, make it usable on stable rust
+/// when the function returning [`RespResult`](axum_resp_result::RespResult)
+///
+/// `expr?` will be rewritten into
+///
+/// ```rust ignore
+/// match ::axum_resp_result::Fallible::to_result(expr) {
+///     Ok(v) => v,
+///     Err(e) => return ::axum_resp_result::FromFailure::from_failure(e),
+/// }
+/// ```
+///
+/// the `?` inside the arguments of the std macros (like `vec![..]` or `format!(..)`)
+/// will be rewritten too, the other macros are left untouched since the `?` may mean something else
+///
+/// the closures, async blocks and nested items will not be rewritten,
+/// since the `return` inside them not return from the function
+pub struct TryRewriter;
+
+impl TryRewriter {
+    /// whether the `?` in the function body need rewriting
+    ///
+    /// the function return a `Result` or an `Option` using the std `?` directly
+    pub fn need_rewrite(output: &ReturnType) -> bool {
+        let ReturnType::Type(_, ty) = output else {
+            return false;
+        };
+        match &**ty {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .map(|seg| seg.ident != "Result" && seg.ident != "Option")
+                .unwrap_or(true),
+            _ => false,
+        }
+    }
+
+    pub fn rewrite(block: &mut Block) {
+        Self.visit_block_mut(block)
+    }
+}
+
+impl VisitMut for TryRewriter {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Closure(_) | Expr::Async(_) | Expr::TryBlock(_) => {}
+            Expr::Try(ExprTry {
+                expr: inner,
+                question_token,
+                ..
+            }) => {
+                self.visit_expr_mut(inner);
+                let span = question_token.span();
+                let body = quote!(#inner);
+                *expr = parse_quote_spanned! {span=>
+                    match ::axum_resp_result::Fallible::to_result(#body) {
+                        ::core::result::Result::Ok(__ok) => __ok,
+                        ::core::result::Result::Err(__err) => {
+                            return ::axum_resp_result::FromFailure::from_failure(__err)
+                        }
+                    }
+                };
+            }
+            _ => syn::visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+
+    fn visit_macro_mut(&mut self, mac: &mut Macro) {
+        let is_std = mac
+            .path
+            .segments
+            .last()
+            .is_some_and(|seg| REWRITE_MACROS.iter().any(|name| seg.ident == name));
+        if !is_std || !contains_try(mac.tokens.clone()) {
+            return;
+        }
+        if let Ok(mut args) = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+            args.iter_mut().for_each(|arg| self.visit_expr_mut(arg));
+            mac.tokens = args.into_token_stream();
+        } else if let Ok((mut elem, semi, mut len)) = mac.parse_body_with(parse_repeat) {
+            self.visit_expr_mut(&mut elem);
+            self.visit_expr_mut(&mut len);
+            mac.tokens = quote!(#elem #semi #len);
+        }
+    }
+
+    fn visit_item_mut(&mut self, _: &mut Item) {}
+}
+
+/// the std macros whose arguments are expressions, the `?` inside them will be rewritten
+const REWRITE_MACROS: [&str; 20] = [
+    "vec",
+    "format",
+    "format_args",
+    "print",
+    "println",
+    "eprint",
+    "eprintln",
+    "write",
+    "writeln",
+    "panic",
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "debug_assert",
+    "debug_assert_eq",
+    "debug_assert_ne",
+    "todo",
+    "unimplemented",
+    "unreachable",
+    "dbg",
+];
+
+/// whether the tokens contain the `?` operator, the `?` inside literals not included
+fn contains_try(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Punct(punct) => punct.as_char() == '?',
+        TokenTree::Group(group) => contains_try(group.stream()),
+        _ => false,
+    })
+}
+
+/// the arguments like `vec![elem; len]`
+fn parse_repeat(input: ParseStream) -> syn::Result<(Expr, Token![;], Expr)> {
+    Ok((input.parse()?, input.parse()?, input.parse()?))
+}
+
+#[cfg(test)]
+mod test {
+    use quote::ToTokens;
+    use syn::{Block, ReturnType};
+
+    use super::TryRewriter;
+
+    #[test]
+    fn test_need_rewrite() {
+        let need =
+            |ret: &str| TryRewriter::need_rewrite(&syn::parse_str::<ReturnType>(ret).unwrap());
+
+        assert!(need("-> RespResult<u32, PlainError>"));
+        assert!(need("-> PlainRResult<u32>"));
+        assert!(!need("-> Result<u32, PlainError>"));
+        assert!(!need("-> std::io::Result<u32>"));
+        assert!(!need(""));
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let mut block = syn::parse_str::<Block>(
+            r#"{
+                let a = foo()?;
+                let f = || bar()?;
+                fn inner() -> Result<(), ()> { baz()? ; Ok(()) }
+                RespResult::Success(a)
+            }"#,
+        )
+        .unwrap();
+        TryRewriter::rewrite(&mut block);
+        let code = block.to_token_stream().to_string();
+
+        assert_eq!(code.matches("Fallible :: to_result").count(), 1);
+        assert!(code.contains("bar () ?"));
+        assert!(code.contains("baz () ?"));
+    }
+
+    #[test]
+    fn test_rewrite_macro() {
+        let mut block = syn::parse_str::<Block>(
+            r#"{
+                let v = vec![foo()?, bar()?];
+                let r = vec![foo()?; len()?];
+                println!("{} {}", foo()?, name = bar()?);
+                std::assert_eq!(foo()?, 1);
+                RespResult::Success(v)
+            }"#,
+        )
+        .unwrap();
+        TryRewriter::rewrite(&mut block);
+        let code = block.to_token_stream().to_string();
+
+        assert_eq!(code.matches("Fallible :: to_result").count(), 7);
+        assert!(!code.contains('?'));
+    }
+
+    #[test]
+    fn test_not_rewrite_macro() {
+        let mut block = syn::parse_str::<Block>(
+            r#"{
+                println!("who? {}", name);
+                my_dsl!(foo()?, bar);
+                matches!(foo()?, Some(_));
+                RespResult::Success(v)
+            }"#,
+        )
+        .unwrap();
+        let expected = block.to_token_stream().to_string();
+        TryRewriter::rewrite(&mut block);
+        let code = block.to_token_stream().to_string();
+
+        assert_eq!(code, expected);
+        assert!(code.contains(r#""who? {}""#));
+    }
+}
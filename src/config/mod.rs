@@ -1,10 +1,14 @@
 mod resp;
 mod status_signed;
+mod timestamp;
 
+pub(crate) use self::resp::InnerRespConfig;
 pub use self::resp::RespConfig;
+pub(crate) use self::serde::InnerSerdeConfig;
 pub use self::serde::SerdeConfig;
 pub use self::status_signed::{SignType, StatusSign};
-use self::{resp::InnerRespConfig, serde::InnerSerdeConfig};
+pub use self::timestamp::TimestampFormat;
+pub(crate) use self::timestamp::{Timestamp, UtcTime};
 
 mod serde;
 
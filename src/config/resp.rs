@@ -1,10 +1,12 @@
-#[allow(unused_imports)]
+#[cfg(any(feature = "extra-error", feature = "context"))]
 use http::header::HeaderName;
-#[allow(unused_imports)]
+#[cfg(any(feature = "extra-error", feature = "context"))]
 use std::borrow::Cow;
+#[cfg(feature = "context")]
+use std::sync::Arc;
 
-#[allow(unused_imports)]
-use crate::owner_leak::OwnerLeaker;
+#[cfg(any(feature = "extra-error", feature = "context"))]
+use crate::expect_ext::ExpectExt;
 /// the config of response
 pub trait RespConfig {
     /// wether write the extra error message into header with the  provided name
@@ -17,19 +19,74 @@ pub trait RespConfig {
     fn head_extra_code(&self) -> Option<Cow<'static, str>> {
         Some("extra-error".into())
     }
+
+    /// the header name of request id, using with [`RespContextLayer`](crate::RespContextLayer)
+    /// - `Some(_)` the request id will be taken from this request header,
+    ///   and echoed as the response header with the same name
+    /// - `None` the request id will always be generated and not echoed
+    ///
+    /// ## Default
+    /// default is enable with name `x-request-id`
+    #[cfg(feature = "context")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "context")))]
+    fn request_id_header(&self) -> Option<Cow<'static, str>> {
+        Some("x-request-id".into())
+    }
+
+    /// the metric name of `Server-Timing` header, using with [`RespContextLayer`](crate::RespContextLayer)
+    /// - `Some(_)` the time spent on the request will be set as `Server-Timing` response header
+    /// - `None` disable
+    ///
+    /// ## Default
+    /// default is disable, the latency of handlers is not exposed to the client
+    #[cfg(feature = "context")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "context")))]
+    fn server_timing_name(&self) -> Option<Cow<'static, str>> {
+        None
+    }
+
+    /// wether the unit-like success ([`Nil`](crate::Nil) or `()`) respond `204 No Content`
+    /// with empty body and without `Content-Type`
+    ///
+    /// the flag [`ExtraFlag::NoContent`](crate::ExtraFlag::NoContent) do the same on a single response
+    ///
+    /// ## Default
+    /// default is disable, respond `200 OK` with `null` body
+    fn unit_no_content(&self) -> bool {
+        false
+    }
 }
 
 pub(crate) struct InnerRespConfig {
     #[cfg(feature = "extra-error")]
     pub(crate) extra_code: Option<HeaderName>,
+    #[cfg(feature = "context")]
+    pub(crate) request_id_header: Option<HeaderName>,
+    #[cfg(feature = "context")]
+    pub(crate) server_timing: Option<Arc<str>>,
+    pub(crate) unit_no_content: bool,
 }
 
 impl InnerRespConfig {
-    #[allow(unused_variables)]
     pub fn into_inner<C: RespConfig>(cfg: &C) -> Self {
         Self {
             #[cfg(feature = "extra-error")]
-            extra_code: cfg.head_extra_code().leak().map(HeaderName::from_static),
+            extra_code: cfg.head_extra_code().map(header_name),
+            #[cfg(feature = "context")]
+            request_id_header: cfg.request_id_header().map(header_name),
+            #[cfg(feature = "context")]
+            server_timing: cfg.server_timing_name().map(Into::into),
+            unit_no_content: cfg.unit_no_content(),
         }
     }
 }
+
+/// ## Panic
+/// the `name` is not a valid header name
+#[cfg(any(feature = "extra-error", feature = "context"))]
+fn header_name(name: Cow<'static, str>) -> HeaderName {
+    match name {
+        Cow::Borrowed(name) => HeaderName::from_static(name),
+        Cow::Owned(name) => HeaderName::try_from(name).with_expect("Bad Header Name"),
+    }
+}
@@ -1,10 +1,10 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, sync::Arc};
 
-use crate::owner_leak::OwnerLeaker;
+use crate::i18n::{InnerI18nConfig, Translator};
 
 use super::{
     status_signed::{InnerStatusSign, SignType},
-    StatusSign,
+    StatusSign, TimestampFormat,
 };
 
 static SIGNED_STATUS: StatusSign = StatusSign {
@@ -15,6 +15,7 @@ static SIGNED_STATUS: StatusSign = StatusSign {
 static EXTRA_ERR_MESSAGE: &str = "extra-error-message";
 static ERROR_MESSAGE: &str = "error-message";
 static BODY: &str = "body";
+static WARNINGS: &str = "warnings";
 
 /// the config information of serialize
 pub trait SerdeConfig {
@@ -58,8 +59,7 @@ pub trait SerdeConfig {
 
     /// extra error message
     /// - Some(_) **enable** extra error message
-    ///- None **disable** extra error message
-    /// extra-error
+    /// - None **disable** extra error message
     ///
     /// ## Default
     /// default enable with field name `extra-msg`
@@ -67,27 +67,149 @@ pub trait SerdeConfig {
     fn extra_message(&self) -> Option<Cow<'static, str>> {
         Some(EXTRA_ERR_MESSAGE.into())
     }
+
+    /// the name of request id field, the value is provided by `RespContextLayer` (feature `context`)
+    /// - Some(_) **enable** request id field, it will be `null` if the request id is not available
+    /// - None **disable** request id field
+    ///
+    /// ## Default
+    /// default disable
+    fn request_id_name(&self) -> Option<Cow<'static, str>> {
+        None
+    }
+
+    /// the name of trace id field, the value is the id of the tracing span
+    /// when the request come into `RespContextLayer` (feature `context`)
+    /// - Some(_) **enable** trace id field, it will be `null` if the trace id is not available
+    /// - None **disable** trace id field
+    ///
+    /// ## Default
+    /// default disable
+    fn trace_id_name(&self) -> Option<Cow<'static, str>> {
+        None
+    }
+
+    /// the name of timestamp field, the value is the time when serializing the response
+    /// - Some(_) **enable** timestamp field
+    /// - None **disable** timestamp field
+    ///
+    /// ## Default
+    /// default disable
+    fn timestamp_name(&self) -> Option<Cow<'static, str>> {
+        None
+    }
+
+    /// the format of timestamp field
+    ///
+    /// ## Default
+    /// default is [`TimestampFormat::Rfc3339`]
+    fn timestamp_format(&self) -> TimestampFormat {
+        TimestampFormat::Rfc3339
+    }
+
+    /// the name of elapsed time field, the value is the milliseconds since
+    /// the request come into `RespContextLayer` (feature `context`)
+    /// - Some(_) **enable** elapsed time field, it will be `null` if the request start time is not available
+    /// - None **disable** elapsed time field
+    ///
+    /// ## Default
+    /// default disable
+    fn elapsed_name(&self) -> Option<Cow<'static, str>> {
+        None
+    }
+
+    /// the name of error details field, the value is provided by [`RespError::resp_details`](crate::RespError::resp_details)
+    /// - Some(_) **enable** error details field
+    /// - None **disable** error details field
+    ///
+    /// ## Default
+    /// default disable
+    fn details_name(&self) -> Option<Cow<'static, str>> {
+        None
+    }
+
+    /// the translator of error message
+    /// - Some(_) **enable** translating the error message which provide [`RespError::resp_message_key`](crate::RespError::resp_message_key)
+    ///   into the locale from `Accept-Language` header, using with `RespContextLayer` (feature `context`)
+    /// - None **disable** translating
+    ///
+    /// ## Default
+    /// default disable
+    fn translator(&self) -> Option<Arc<dyn Translator>> {
+        None
+    }
+
+    /// the locale using when no locale accepted by the client can be translated
+    ///
+    /// ## Default
+    /// default is `None`, the untranslated [`RespError::resp_message`](crate::RespError::resp_message) will be used
+    fn fallback_locale(&self) -> Option<Cow<'static, str>> {
+        None
+    }
+
+    /// the placement of the page metadata provided by [`RespBody::page_meta`](crate::RespBody::page_meta)
+    /// - Some(_) the metadata will be a sibling field of body with this name,
+    ///   it will be `null` if the metadata is not available
+    /// - None the metadata will be serialized inside the body, alongside the `items`
+    ///
+    /// ## Default
+    /// default inside the body
+    fn page_meta_name(&self) -> Option<Cow<'static, str>> {
+        None
+    }
+
+    /// the name of warnings field, the value is provided by [`RespBody::warnings`](crate::RespBody::warnings)
+    /// - Some(_) **enable** warnings field, it only appears when the [`RespResult`](crate::RespResult)
+    ///   is `Success(_)` with any warning
+    /// - None **disable** warnings field
+    ///
+    /// ## Default
+    /// the default field name is `warnings`
+    fn warnings_name(&self) -> Option<Cow<'static, str>> {
+        Some(WARNINGS.into())
+    }
 }
 
 pub(crate) struct InnerSerdeConfig {
-    pub(crate) body_name: &'static str,
-    pub(crate) err_msg_name: &'static str,
+    pub(crate) body_name: Arc<str>,
+    pub(crate) err_msg_name: Arc<str>,
     pub(crate) full_field: bool,
     pub(crate) signed_status: Option<InnerStatusSign>,
     #[cfg(feature = "extra-error")]
-    pub(crate) extra_code: Option<&'static str>,
+    pub(crate) extra_code: Option<Arc<str>>,
+    pub(crate) request_id: Option<Arc<str>>,
+    pub(crate) trace_id: Option<Arc<str>>,
+    pub(crate) timestamp: Option<(Arc<str>, TimestampFormat)>,
+    pub(crate) elapsed: Option<Arc<str>>,
+    pub(crate) details: Option<Arc<str>>,
+    pub(crate) i18n: Option<InnerI18nConfig>,
+    pub(crate) page_meta: Option<Arc<str>>,
+    pub(crate) warnings: Option<Arc<str>>,
     pub(crate) field_size: FieldSize,
 }
 
 impl InnerSerdeConfig {
     pub(crate) fn into_inner<C: SerdeConfig>(cfg: &C) -> Self {
         let mut s = Self {
-            body_name: cfg.body_name().leak(),
-            err_msg_name: cfg.err_msg_name().leak(),
+            body_name: cfg.body_name().into(),
+            err_msg_name: cfg.err_msg_name().into(),
             full_field: cfg.fixed_field(),
             signed_status: cfg.signed_status().map(Into::into),
             #[cfg(feature = "extra-error")]
-            extra_code: cfg.extra_message().leak(),
+            extra_code: cfg.extra_message().map(Into::into),
+            request_id: cfg.request_id_name().map(Into::into),
+            trace_id: cfg.trace_id_name().map(Into::into),
+            timestamp: cfg
+                .timestamp_name()
+                .map(|name| (name.into(), cfg.timestamp_format())),
+            elapsed: cfg.elapsed_name().map(Into::into),
+            details: cfg.details_name().map(Into::into),
+            i18n: cfg.translator().map(|translator| InnerI18nConfig {
+                translator,
+                fallback_locale: cfg.fallback_locale().map(Into::into),
+            }),
+            page_meta: cfg.page_meta_name().map(Into::into),
+            warnings: cfg.warnings_name().map(Into::into),
             field_size: Default::default(),
         };
 
@@ -98,15 +220,23 @@ impl InnerSerdeConfig {
     }
 
     pub(crate) fn get_field_size(&self) -> (usize, usize) {
-        let FieldSize { ok_size, err_size } = self.field_size;
+        let FieldSize {
+            ok_size, err_size, ..
+        } = self.field_size;
         (ok_size, err_size)
     }
+
+    /// the number of the request context fields in the field size
+    pub(crate) fn get_context_size(&self) -> usize {
+        self.field_size.context_size
+    }
 }
 
 #[derive(Debug, Default)]
 pub(crate) struct FieldSize {
     ok_size: usize,
     err_size: usize,
+    context_size: usize,
 }
 
 impl FieldSize {
@@ -117,6 +247,25 @@ impl FieldSize {
             ok_size += 1;
             err_size += 1;
         }
+        // 请求上下文
+        let context_size = [
+            cfg.request_id.is_some(),
+            cfg.trace_id.is_some(),
+            cfg.timestamp.is_some(),
+            cfg.elapsed.is_some(),
+        ]
+        .into_iter()
+        .filter(|enable| *enable)
+        .count();
+        ok_size += context_size;
+        err_size += context_size;
+        // 错误详情
+        if cfg.details.is_some() {
+            if cfg.full_field {
+                ok_size += 1;
+            }
+            err_size += 1;
+        }
         //额外的异常码
         #[cfg(feature = "extra-error")]
         if cfg.extra_code.is_some() {
@@ -126,10 +275,20 @@ impl FieldSize {
             err_size += 1;
         }
 
+        // 分页信息
+        if cfg.page_meta.is_some() && cfg.full_field {
+            ok_size += 1;
+            err_size += 1;
+        }
+
         if cfg.full_field {
             ok_size += 1;
             err_size += 1;
         }
-        Self { ok_size, err_size }
+        Self {
+            ok_size,
+            err_size,
+            context_size,
+        }
     }
 }
@@ -1,9 +1,7 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, sync::Arc};
 
 use serde::Serialize;
 
-use crate::owner_leak::OwnerLeaker;
-
 #[derive(Debug, Clone)]
 /// the full info of status sign
 pub struct StatusSign {
@@ -74,7 +72,7 @@ impl SignType {
 }
 
 pub(crate) struct InnerStatusSign {
-    pub(crate) field: &'static str,
+    pub(crate) field: Arc<str>,
     pub(crate) ok: StatusEnum,
     pub(crate) err: StatusEnum,
 }
@@ -91,7 +89,7 @@ impl From<StatusSign> for InnerStatusSign {
         };
 
         Self {
-            field: field_name.leak(),
+            field: field_name.into(),
             ok,
             err,
         }
@@ -0,0 +1,141 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// the format of the timestamp field
+pub enum TimestampFormat {
+    /// using RFC 3339 format with milliseconds, like `2018-02-14T00:28:07.123Z`
+    #[default]
+    Rfc3339,
+    /// using the milliseconds since unix epoch
+    UnixMillis,
+}
+
+pub(crate) struct Timestamp {
+    pub(crate) time: SystemTime,
+    pub(crate) format: TimestampFormat,
+}
+
+impl Timestamp {
+    pub(crate) fn now(format: TimestampFormat) -> Self {
+        Self {
+            time: SystemTime::now(),
+            format,
+        }
+    }
+}
+
+impl Serialize for Timestamp {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self.format {
+            TimestampFormat::Rfc3339 => {
+                serializer.collect_str(&UtcTime::new(self.time).rfc3339_millis())
+            }
+            TimestampFormat::UnixMillis => {
+                let millis = self
+                    .time
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_millis() as u64)
+                    .unwrap_or_default();
+                serializer.serialize_u64(millis)
+            }
+        }
+    }
+}
+
+/// the broken-down UTC time, the time before unix epoch is clamped to the epoch
+pub(crate) struct UtcTime {
+    year: u64,
+    month: u64,
+    day: u64,
+    hour: u64,
+    minute: u64,
+    second: u64,
+    millis: u32,
+    /// `0` is Sunday
+    weekday: u64,
+}
+
+impl UtcTime {
+    pub(crate) fn new(time: SystemTime) -> Self {
+        let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let secs = since_epoch.as_secs();
+        let days = secs / 86400;
+
+        // the civil date from days since epoch
+        // see <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+        let z = days + 719_468;
+        let era = z / 146_097;
+        let doe = z % 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + u64::from(month <= 2);
+
+        Self {
+            year,
+            month,
+            day,
+            hour: secs % 86400 / 3600,
+            minute: secs % 3600 / 60,
+            second: secs % 60,
+            millis: since_epoch.subsec_millis(),
+            // 1970-01-01 is Thursday
+            weekday: (days + 4) % 7,
+        }
+    }
+
+    /// format as RFC 3339 with milliseconds, like `2018-02-14T00:28:07.123Z`
+    pub(crate) fn rfc3339_millis(&self) -> String {
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+            self.year, self.month, self.day, self.hour, self.minute, self.second, self.millis
+        )
+    }
+
+    /// format as HTTP date ([RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#section-5.6.7)),
+    /// like `Fri, 30 Jun 2023 23:59:59 GMT`
+    pub(crate) fn http_date(&self) -> String {
+        const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+        const MONTHS: [&str; 12] = [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ];
+        format!(
+            "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+            WEEKDAYS[self.weekday as usize],
+            self.day,
+            MONTHS[self.month as usize - 1],
+            self.year,
+            self.hour,
+            self.minute,
+            self.second
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::UtcTime;
+
+    #[test]
+    fn test_utc_time_format() {
+        let time = UtcTime::new(UNIX_EPOCH + Duration::from_millis(1_518_568_087_123));
+        assert_eq!(time.rfc3339_millis(), "2018-02-14T00:28:07.123Z");
+        assert_eq!(time.http_date(), "Wed, 14 Feb 2018 00:28:07 GMT");
+
+        let time = UtcTime::new(UNIX_EPOCH + Duration::from_secs(951_782_400));
+        assert_eq!(time.rfc3339_millis(), "2000-02-29T00:00:00.000Z");
+
+        let time = UtcTime::new(UNIX_EPOCH);
+        assert_eq!(time.http_date(), "Thu, 01 Jan 1970 00:00:00 GMT");
+    }
+}
@@ -0,0 +1,182 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use http::{
+    header::{HeaderName, ACCEPT_LANGUAGE},
+    HeaderValue, Request, Response,
+};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::{get_config, i18n::parse_accept_language};
+
+use super::{RequestContext, REQUEST_CONTEXT};
+
+static SERVER_TIMING: HeaderName = HeaderName::from_static("server-timing");
+
+/// the layer providing [`RequestContext`] for each request
+///
+/// - the request id is taken from the request header set by [`RespConfig::request_id_header`](crate::RespConfig::request_id_header),
+///   or generated if absent
+/// - the request id will be echoed as the response header with the same name
+/// - the locales accepted by the client are taken from `Accept-Language` header
+/// - the time spent will be set as `Server-Timing` header if [`RespConfig::server_timing_name`](crate::RespConfig::server_timing_name) enabled
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RespContextLayer;
+
+impl RespContextLayer {
+    /// create a new [`RespContextLayer`]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl<S> Layer<S> for RespContextLayer {
+    type Service = RespContextService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RespContextService { inner }
+    }
+}
+
+/// the service created by [`RespContextLayer`]
+#[derive(Debug, Clone)]
+pub struct RespContextService<S> {
+    inner: S,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for RespContextService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+
+    type Error = S::Error;
+
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let cfg = &get_config().resp;
+        let header = cfg.request_id_header.as_ref();
+        let ctx = header
+            .and_then(|name| req.headers().get(name))
+            .and_then(|value| value.to_str().ok())
+            .filter(|id| !id.is_empty())
+            .map(RequestContext::new)
+            .unwrap_or_else(RequestContext::generate);
+        let locales = req
+            .headers()
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .map(parse_accept_language)
+            .unwrap_or_default();
+        let ctx = ctx.with_locales(locales);
+
+        let future = REQUEST_CONTEXT.sync_scope(ctx.clone(), || self.inner.call(req));
+
+        Box::pin(async move {
+            let mut resp = ctx.clone().scope(future).await?;
+            if let Some(name) = header {
+                if let Ok(value) = HeaderValue::from_str(ctx.request_id()) {
+                    resp.headers_mut().insert(name.clone(), value);
+                }
+            }
+            if let Some(value) = cfg
+                .server_timing
+                .as_deref()
+                .and_then(|name| server_timing(name, &ctx))
+            {
+                resp.headers_mut().append(&SERVER_TIMING, value);
+            }
+            Ok(resp)
+        })
+    }
+}
+
+/// the `Server-Timing` header value of the time spent by `ctx`
+fn server_timing(name: &str, ctx: &RequestContext) -> Option<HeaderValue> {
+    let timing = format!("{name};dur={:.3}", ctx.elapsed().as_secs_f64() * 1000.0);
+    HeaderValue::from_str(&timing).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use std::convert::Infallible;
+
+    use http::{Request, Response};
+    use tower::{service_fn, ServiceExt};
+    use tower_layer::Layer;
+
+    use crate::context::RequestContext;
+
+    use super::{server_timing, RespContextLayer};
+
+    async fn handle(_: Request<()>) -> Result<Response<String>, Infallible> {
+        let id = RequestContext::current()
+            .map(|ctx| ctx.request_id().to_owned())
+            .unwrap_or_default();
+        Ok(Response::new(id))
+    }
+
+    #[tokio::test]
+    async fn test_echo_request_id() {
+        let service = RespContextLayer.layer(service_fn(handle));
+        let req = Request::builder()
+            .header("x-request-id", "req-0001")
+            .body(())
+            .unwrap();
+
+        let resp = service.oneshot(req).await.unwrap();
+
+        assert_eq!(resp.body(), "req-0001");
+        assert_eq!(resp.headers()["x-request-id"], "req-0001");
+    }
+
+    #[tokio::test]
+    async fn test_generate_request_id() {
+        let service = RespContextLayer.layer(service_fn(handle));
+
+        let resp = service.oneshot(Request::new(())).await.unwrap();
+
+        assert!(!resp.body().is_empty());
+        assert_eq!(resp.headers()["x-request-id"], resp.body().as_str());
+    }
+
+    #[tokio::test]
+    async fn test_accept_language() {
+        let service = RespContextLayer.layer(service_fn(|_: Request<()>| async {
+            let locales = RequestContext::current()
+                .map(|ctx| ctx.locales().join(","))
+                .unwrap_or_default();
+            Ok::<_, Infallible>(Response::new(locales))
+        }));
+        let req = Request::builder()
+            .header("accept-language", "en;q=0.5, zh-CN")
+            .body(())
+            .unwrap();
+
+        let resp = service.oneshot(req).await.unwrap();
+
+        assert_eq!(resp.body(), "zh-CN,en");
+    }
+
+    #[tokio::test]
+    async fn test_server_timing() {
+        let service = RespContextLayer.layer(service_fn(handle));
+
+        let resp = service.oneshot(Request::new(())).await.unwrap();
+        assert!(resp.headers().get("server-timing").is_none());
+
+        let timing = server_timing("app", &RequestContext::new("abc")).unwrap();
+        assert!(timing.to_str().unwrap().starts_with("app;dur="));
+    }
+}
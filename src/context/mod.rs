@@ -0,0 +1,134 @@
+#[cfg(feature = "context")]
+mod layer;
+
+#[cfg(feature = "context")]
+use std::future::Future;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "context")]
+pub use self::layer::{RespContextLayer, RespContextService};
+
+#[cfg(feature = "context")]
+tokio::task_local! {
+    static REQUEST_CONTEXT: RequestContext;
+}
+
+/// the context of the request currently handling
+///
+/// it is available when the handler is wrapped by `RespContextLayer` (require feature `context`),
+/// and will be written into the response body according to [`SerdeConfig`](crate::SerdeConfig)
+#[derive(Debug, Clone)]
+pub struct RequestContext {
+    request_id: Arc<str>,
+    trace_id: Option<u64>,
+    start: Instant,
+    locales: Arc<[Arc<str>]>,
+}
+
+impl RequestContext {
+    /// create a new [`RequestContext`] with provide `request_id`
+    ///
+    /// the trace id will be the id of the current [`tracing`](https://docs.rs/tracing) span
+    /// if feature `tracing` is enabled, and the request start at now
+    pub fn new(request_id: impl Into<Arc<str>>) -> Self {
+        Self {
+            request_id: request_id.into(),
+            trace_id: current_trace_id(),
+            start: Instant::now(),
+            locales: Arc::new([]),
+        }
+    }
+
+    /// set the locales accepted by the client, the earlier the more preferred
+    pub fn with_locales<I>(mut self, locales: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Arc<str>>,
+    {
+        self.locales = locales.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// create a new [`RequestContext`] with a generated request id
+    #[cfg(feature = "context")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "context")))]
+    pub fn generate() -> Self {
+        Self::new(uuid::Uuid::new_v4().to_string())
+    }
+
+    /// the id of current request
+    pub fn request_id(&self) -> &str {
+        &self.request_id
+    }
+
+    /// the id of the span when the request coming
+    pub fn trace_id(&self) -> Option<u64> {
+        self.trace_id
+    }
+
+    /// the time when the request start
+    pub fn start(&self) -> Instant {
+        self.start
+    }
+
+    /// the locales accepted by the client, the earlier the more preferred
+    pub fn locales(&self) -> &[Arc<str>] {
+        &self.locales
+    }
+
+    /// the time elapsed since the request start
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// get the [`RequestContext`] of current request
+    /// - `Some(_)` if current task is running in a request scope
+    /// - `None` otherwise, it is always `None` without feature `context`
+    pub fn current() -> Option<Self> {
+        #[cfg(feature = "context")]
+        {
+            REQUEST_CONTEXT.try_with(Clone::clone).ok()
+        }
+        #[cfg(not(feature = "context"))]
+        {
+            None
+        }
+    }
+
+    /// running the `future` with this [`RequestContext`] as the context of current request
+    #[cfg(feature = "context")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "context")))]
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        REQUEST_CONTEXT.scope(self, future).await
+    }
+}
+
+#[cfg(feature = "tracing")]
+fn current_trace_id() -> Option<u64> {
+    trace::Span::current().id().map(|id| id.into_u64())
+}
+
+#[cfg(not(feature = "tracing"))]
+fn current_trace_id() -> Option<u64> {
+    None
+}
+
+#[cfg(all(test, feature = "context"))]
+mod test {
+    use super::RequestContext;
+
+    #[tokio::test]
+    async fn test_scope() {
+        assert!(RequestContext::current().is_none());
+
+        let id = RequestContext::new("abc")
+            .scope(async { RequestContext::current().map(|ctx| ctx.request_id().to_owned()) })
+            .await;
+
+        assert_eq!(id.as_deref(), Some("abc"));
+        assert!(RequestContext::current().is_none());
+    }
+}
@@ -0,0 +1,303 @@
+use std::borrow::Cow;
+
+use actix_web::{dev::Payload, FromRequest, HttpRequest, HttpResponse, ResponseError};
+use futures::future::LocalBoxFuture;
+use http::StatusCode;
+
+use crate::{
+    resp_result::to_response::actix::actix_response, ErrorDetail, MessageKey, Nil, RespError,
+    RespResult, ValidationErrors,
+};
+
+use super::{FromRequestFamily, MapReject, RespRejection, ToInner, Valid, Validator};
+
+impl<E: RespError> ResponseError for RespRejection<E> {
+    fn status_code(&self) -> actix_web::http::StatusCode {
+        actix_web::http::StatusCode::from_u16(self.0.http_code().as_u16())
+            .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        // the `&self` can not move the error out, responding the borrowed one
+        actix_response(&RespResult::<Nil, _>::Err(ErrRef(&self.0)))
+    }
+}
+
+/// the borrowed [`RespError`], forwarding everything to the inner error
+struct ErrRef<'a, E>(&'a E);
+
+impl<E: RespError> RespError for ErrRef<'_, E> {
+    #[inline]
+    fn log_message(&self) -> Cow<'_, str> {
+        self.0.log_message()
+    }
+
+    #[inline]
+    fn resp_message(&self) -> Cow<'_, str> {
+        self.0.resp_message()
+    }
+
+    #[inline]
+    fn resp_message_key(&self) -> Option<MessageKey<'_>> {
+        self.0.resp_message_key()
+    }
+
+    #[inline]
+    fn resp_details(&self) -> Option<Cow<'_, [ErrorDetail]>> {
+        self.0.resp_details()
+    }
+
+    #[inline]
+    fn http_code(&self) -> StatusCode {
+        self.0.http_code()
+    }
+
+    #[cfg(feature = "tonic")]
+    #[inline]
+    fn grpc_code(&self) -> tonic::Code {
+        self.0.grpc_code()
+    }
+
+    #[cfg(feature = "extra-error")]
+    type ExtraMessage = E::ExtraMessage;
+
+    #[cfg(feature = "extra-error")]
+    #[inline]
+    fn extra_message(&self) -> Self::ExtraMessage {
+        self.0.extra_message()
+    }
+
+    #[inline]
+    fn resp_message_default() -> Option<Cow<'static, str>> {
+        E::resp_message_default()
+    }
+
+    #[cfg(feature = "extra-error")]
+    #[inline]
+    fn extra_message_default() -> Option<Self::ExtraMessage> {
+        E::extra_message_default()
+    }
+}
+
+/// the rejections of actix-web extractors, the response message is sanitized by the status code
+impl RespError for actix_web::Error {
+    fn log_message(&self) -> Cow<'_, str> {
+        self.to_string().into()
+    }
+
+    fn resp_message(&self) -> Cow<'_, str> {
+        let status = self.http_code();
+        match status.canonical_reason() {
+            Some(reason) if status.is_client_error() => reason.into(),
+            _ => "Internal Server Error".into(),
+        }
+    }
+
+    fn http_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.as_response_error().status_code().as_u16())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    #[cfg(feature = "extra-error")]
+    type ExtraMessage = crate::Nil;
+
+    #[cfg(feature = "extra-error")]
+    fn extra_message(&self) -> Self::ExtraMessage {
+        crate::Nil
+    }
+}
+
+impl<T, E> FromRequest for MapReject<T, E>
+where
+    E: From<<T::Payload as FromRequest>::Error> + RespError + 'static,
+    T: FromRequestFamily<E>,
+    T::Payload: FromRequest + 'static,
+{
+    type Error = RespRejection<E>;
+
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = <T::Payload as FromRequest>::from_request(req, payload);
+        Box::pin(async move {
+            fut.await
+                .map_err(RespRejection::<E>::new)
+                .map(|data| Self(data.to_inner()))
+        })
+    }
+}
+
+impl<T, E, V> FromRequest for Valid<T, E, V>
+where
+    E: From<<T::Payload as FromRequest>::Error> + From<ValidationErrors> + RespError + 'static,
+    T: FromRequestFamily<E>,
+    T::Payload: FromRequest + 'static,
+    V: Validator<<T::Payload as ToInner>::Inner>,
+{
+    type Error = RespRejection<E>;
+
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = <T::Payload as FromRequest>::from_request(req, payload);
+        Box::pin(async move {
+            let data = fut.await.map_err(RespRejection::<E>::new)?.to_inner();
+            V::validate(&data).map_err(RespRejection::<E>::new)?;
+            Ok(Self::new(data))
+        })
+    }
+}
+
+mod from_request_families {
+    use std::sync::Arc;
+
+    use actix_web::web::{Data, Form, Json, Path, Query};
+
+    use crate::convert::from_request::ToInner;
+
+    impl<T> ToInner for Json<T> {
+        type Inner = T;
+
+        fn to_inner(self) -> Self::Inner {
+            self.into_inner()
+        }
+    }
+
+    impl<T> ToInner for Path<T> {
+        type Inner = T;
+
+        fn to_inner(self) -> Self::Inner {
+            self.into_inner()
+        }
+    }
+
+    impl<T> ToInner for Query<T> {
+        type Inner = T;
+
+        fn to_inner(self) -> Self::Inner {
+            self.into_inner()
+        }
+    }
+
+    impl<T> ToInner for Form<T> {
+        type Inner = T;
+
+        fn to_inner(self) -> Self::Inner {
+            self.into_inner()
+        }
+    }
+
+    impl<T: ?Sized> ToInner for Data<T> {
+        type Inner = Arc<T>;
+
+        fn to_inner(self) -> Self::Inner {
+            self.into_inner()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::{body::to_bytes, test::TestRequest, web::Json, FromRequest, ResponseError};
+    use http::StatusCode;
+    use serde::Deserialize;
+
+    use crate::{MapReject, RespError, Valid, Validate, ValidationErrors};
+
+    #[derive(Debug, Deserialize)]
+    struct Input {
+        num: i32,
+    }
+
+    impl Validate for Input {
+        fn validate(&self) -> Result<(), ValidationErrors> {
+            let mut errors = ValidationErrors::new();
+            if self.num < 0 {
+                errors.add("num", "range", "num must not be negative");
+            }
+            errors.into_result()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_map_reject() {
+        let (req, mut payload) = TestRequest::post()
+            .insert_header(("content-type", "application/json"))
+            .set_payload(r#"{"num":1}"#)
+            .to_http_parts();
+        let MapReject(input) =
+            MapReject::<Json<Input>, actix_web::Error>::from_request(&req, &mut payload)
+                .await
+                .unwrap();
+        assert_eq!(input.num, 1);
+
+        let (req, mut payload) = TestRequest::post().set_payload("abc").to_http_parts();
+        let Err(err) =
+            MapReject::<Json<Input>, actix_web::Error>::from_request(&req, &mut payload).await
+        else {
+            panic!("expect rejection")
+        };
+        let resp = err.error_response();
+        assert_eq!(resp.status().as_u16(), 400);
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        assert!(std::str::from_utf8(&body).unwrap().contains("Bad Request"));
+    }
+
+    #[tokio::test]
+    async fn test_valid() {
+        let (req, mut payload) = TestRequest::post()
+            .insert_header(("content-type", "application/json"))
+            .set_payload(r#"{"num":-1}"#)
+            .to_http_parts();
+        let Err(err) = Valid::<Json<Input>, RejectErr>::from_request(&req, &mut payload).await
+        else {
+            panic!("expect rejection")
+        };
+        assert_eq!(
+            err.into_inner().http_code(),
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+    }
+
+    #[derive(Debug)]
+    enum RejectErr {
+        Actix(actix_web::Error),
+        Validate(ValidationErrors),
+    }
+
+    impl From<actix_web::Error> for RejectErr {
+        fn from(err: actix_web::Error) -> Self {
+            Self::Actix(err)
+        }
+    }
+
+    impl From<ValidationErrors> for RejectErr {
+        fn from(err: ValidationErrors) -> Self {
+            Self::Validate(err)
+        }
+    }
+
+    impl RespError for RejectErr {
+        fn log_message(&self) -> std::borrow::Cow<'_, str> {
+            match self {
+                RejectErr::Actix(err) => err.log_message(),
+                RejectErr::Validate(err) => err.log_message(),
+            }
+        }
+
+        fn http_code(&self) -> StatusCode {
+            match self {
+                RejectErr::Actix(err) => err.http_code(),
+                RejectErr::Validate(err) => err.http_code(),
+            }
+        }
+
+        #[cfg(feature = "extra-error")]
+        type ExtraMessage = crate::Nil;
+
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Self::ExtraMessage {
+            crate::Nil
+        }
+    }
+}
@@ -5,7 +5,8 @@ use futures::TryFutureExt;
 
 use crate::{Nil, RespError, RespResult};
 
-use super::{FromRequestFamily, MapReject, ToInner};
+use super::{FromRequestFamily, MapReject, ToInner, Valid, Validator};
+use crate::ValidationErrors;
 
 impl<S, T, E> FromRequest<S> for MapReject<T, E>
 where
@@ -65,8 +66,76 @@ where
         })
     }
 }
+impl<S, T, E, V> FromRequest<S> for Valid<T, E, V>
+where
+    S: Sync,
+    E: Send + From<<T::Payload as FromRequest<S>>::Rejection> + From<ValidationErrors> + RespError,
+    T: FromRequestFamily<E>,
+    T::Payload: FromRequest<S>,
+    V: Validator<<T::Payload as ToInner>::Inner>,
+{
+    type Rejection = RespResult<Nil, E>;
+
+    fn from_request<'life0, 'async_trait>(
+        req: Request,
+        state: &'life0 S,
+    ) -> Pin<Box<dyn Future<Output = Result<Self, Self::Rejection>> + Send + 'async_trait>>
+    where
+        'life0: 'async_trait,
+        Self: 'async_trait,
+    {
+        Box::pin(async {
+            let data = <T::Payload as FromRequest<S>>::from_request(req, state)
+                .await
+                .map_err(|err| RespResult::Err(E::from(err)))?
+                .to_inner();
+            V::validate(&data).map_err(|err| RespResult::Err(E::from(err)))?;
+            Ok(Self::new(data))
+        })
+    }
+}
+
+impl<S, T, E, V> FromRequestParts<S> for Valid<T, E, V>
+where
+    S: Sync,
+    E: Send
+        + From<<T::Payload as FromRequestParts<S>>::Rejection>
+        + From<ValidationErrors>
+        + RespError,
+    T: FromRequestFamily<E>,
+    T::Payload: FromRequestParts<S>,
+    V: Validator<<T::Payload as ToInner>::Inner>,
+{
+    type Rejection = RespResult<Nil, E>;
+
+    fn from_request_parts<'life0, 'life1, 'async_trait>(
+        parts: &'life0 mut http::request::Parts,
+        state: &'life1 S,
+    ) -> Pin<Box<dyn Future<Output = Result<Self, Self::Rejection>> + Send + 'async_trait>>
+    where
+        'life0: 'async_trait,
+        'life1: 'async_trait,
+        Self: 'async_trait,
+    {
+        Box::pin(async {
+            let data = <T::Payload as FromRequestParts<S>>::from_request_parts(parts, state)
+                .await
+                .map_err(|err| RespResult::Err(E::from(err)))?
+                .to_inner();
+            V::validate(&data).map_err(|err| RespResult::Err(E::from(err)))?;
+            Ok(Self::new(data))
+        })
+    }
+}
+
 mod from_request_families {
-    use axum::extract::{Extension, Form, Json, Path, Query, State};
+    use axum::{
+        body::Bytes,
+        extract::{
+            ConnectInfo, Extension, Form, Json, MatchedPath, NestedPath, OriginalUri, Path, Query,
+            RawForm, RawQuery, State,
+        },
+    };
 
     use crate::convert::from_request::ToInner;
 
@@ -117,4 +186,421 @@ mod from_request_families {
             self.0
         }
     }
+
+    impl ToInner for RawQuery {
+        type Inner = Option<String>;
+
+        fn to_inner(self) -> Self::Inner {
+            self.0
+        }
+    }
+
+    impl ToInner for RawForm {
+        type Inner = Bytes;
+
+        fn to_inner(self) -> Self::Inner {
+            self.0
+        }
+    }
+
+    impl ToInner for Bytes {
+        type Inner = Bytes;
+
+        fn to_inner(self) -> Self::Inner {
+            self
+        }
+    }
+
+    impl ToInner for String {
+        type Inner = String;
+
+        fn to_inner(self) -> Self::Inner {
+            self
+        }
+    }
+
+    impl<T> ToInner for ConnectInfo<T> {
+        type Inner = T;
+
+        fn to_inner(self) -> Self::Inner {
+            self.0
+        }
+    }
+
+    impl ToInner for MatchedPath {
+        type Inner = MatchedPath;
+
+        fn to_inner(self) -> Self::Inner {
+            self
+        }
+    }
+
+    impl ToInner for OriginalUri {
+        type Inner = http::Uri;
+
+        fn to_inner(self) -> Self::Inner {
+            self.0
+        }
+    }
+
+    impl ToInner for NestedPath {
+        type Inner = NestedPath;
+
+        fn to_inner(self) -> Self::Inner {
+            self
+        }
+    }
+
+    #[cfg(feature = "multipart")]
+    impl ToInner for axum::extract::Multipart {
+        type Inner = axum::extract::Multipart;
+
+        fn to_inner(self) -> Self::Inner {
+            self
+        }
+    }
+
+    #[cfg(feature = "ws")]
+    impl ToInner for axum::extract::WebSocketUpgrade {
+        type Inner = axum::extract::WebSocketUpgrade;
+
+        fn to_inner(self) -> Self::Inner {
+            self
+        }
+    }
+
+    #[cfg(feature = "typed-header")]
+    impl<T> ToInner for axum_extra::TypedHeader<T> {
+        type Inner = T;
+
+        fn to_inner(self) -> Self::Inner {
+            self.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use axum::extract::{
+        rejection::{JsonRejection, QueryRejection},
+        FromRequest, FromRequestParts, Json, Query, Request,
+    };
+    use http::StatusCode;
+    use serde::Deserialize;
+
+    use crate::{MapReject, RespError, RespResult, Valid, Validate, ValidationErrors};
+
+    #[derive(Debug, Deserialize)]
+    struct Input {
+        name: String,
+    }
+
+    impl Validate for Input {
+        fn validate(&self) -> Result<(), ValidationErrors> {
+            let mut errors = ValidationErrors::new();
+            if self.name.is_empty() {
+                errors.add("name", "required", "name is required");
+            }
+            errors.into_result()
+        }
+    }
+
+    enum MockErr {
+        Json(JsonRejection),
+        Query(QueryRejection),
+        Invalid(ValidationErrors),
+    }
+
+    impl From<JsonRejection> for MockErr {
+        fn from(err: JsonRejection) -> Self {
+            Self::Json(err)
+        }
+    }
+
+    impl From<QueryRejection> for MockErr {
+        fn from(err: QueryRejection) -> Self {
+            Self::Query(err)
+        }
+    }
+
+    impl From<ValidationErrors> for MockErr {
+        fn from(err: ValidationErrors) -> Self {
+            Self::Invalid(err)
+        }
+    }
+
+    impl RespError for MockErr {
+        fn log_message(&self) -> Cow<'_, str> {
+            match self {
+                MockErr::Json(err) => err.body_text().into(),
+                MockErr::Query(err) => err.body_text().into(),
+                MockErr::Invalid(err) => err.log_message(),
+            }
+        }
+
+        fn http_code(&self) -> StatusCode {
+            match self {
+                MockErr::Json(err) => err.status(),
+                MockErr::Query(err) => err.status(),
+                MockErr::Invalid(err) => err.http_code(),
+            }
+        }
+
+        #[cfg(feature = "extra-error")]
+        type ExtraMessage = String;
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Self::ExtraMessage {
+            String::new()
+        }
+    }
+
+    fn json_request(body: &'static str) -> Request {
+        Request::builder()
+            .header("content-type", "application/json")
+            .body(body.into())
+            .unwrap()
+    }
+
+    fn status<T>(result: Result<T, RespResult<crate::Nil, MockErr>>) -> Option<StatusCode> {
+        match result {
+            Ok(_) => None,
+            Err(RespResult::Err(err)) => Some(err.http_code()),
+            Err(RespResult::Success(_)) => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_valid_json() {
+        let Valid(input, ..) =
+            Valid::<Json<Input>, MockErr>::from_request(json_request(r#"{"name":"foo"}"#), &())
+                .await
+                .unwrap();
+        assert_eq!(input.name, "foo");
+
+        let ret =
+            Valid::<Json<Input>, MockErr>::from_request(json_request(r#"{"name":""}"#), &()).await;
+        assert_eq!(status(ret), Some(StatusCode::UNPROCESSABLE_ENTITY));
+
+        let ret = Valid::<Json<Input>, MockErr>::from_request(json_request("{"), &()).await;
+        assert_eq!(status(ret), Some(StatusCode::BAD_REQUEST));
+    }
+
+    #[tokio::test]
+    async fn test_valid_query() {
+        let (mut parts, _) = Request::builder()
+            .uri("/?name=")
+            .body(())
+            .unwrap()
+            .into_parts();
+        let ret = Valid::<Query<Input>, MockErr>::from_request_parts(&mut parts, &()).await;
+        assert_eq!(status(ret), Some(StatusCode::UNPROCESSABLE_ENTITY));
+
+        let (mut parts, _) = Request::builder().uri("/").body(()).unwrap().into_parts();
+        let ret = Valid::<Query<Input>, MockErr>::from_request_parts(&mut parts, &()).await;
+        assert_eq!(status(ret), Some(StatusCode::BAD_REQUEST));
+    }
+
+    struct RejectErr(StatusCode);
+
+    macro_rules! reject_from {
+        ($($rejection:ty),* $(,)?) => {
+            $(
+                impl From<$rejection> for RejectErr {
+                    fn from(err: $rejection) -> Self {
+                        Self(err.status())
+                    }
+                }
+            )*
+        };
+    }
+
+    reject_from!(
+        axum::extract::rejection::BytesRejection,
+        axum::extract::rejection::StringRejection,
+        axum::extract::rejection::RawFormRejection,
+        axum::extract::rejection::ExtensionRejection,
+        axum::extract::rejection::MatchedPathRejection,
+        axum::extract::rejection::NestedPathRejection,
+    );
+    #[cfg(feature = "multipart")]
+    reject_from!(axum::extract::multipart::MultipartRejection);
+    #[cfg(feature = "ws")]
+    reject_from!(axum::extract::ws::rejection::WebSocketUpgradeRejection);
+
+    #[cfg(feature = "typed-header")]
+    impl From<axum_extra::typed_header::TypedHeaderRejection> for RejectErr {
+        fn from(err: axum_extra::typed_header::TypedHeaderRejection) -> Self {
+            use axum::response::IntoResponse;
+            Self(err.into_response().status())
+        }
+    }
+
+    impl From<std::convert::Infallible> for RejectErr {
+        fn from(err: std::convert::Infallible) -> Self {
+            match err {}
+        }
+    }
+
+    impl RespError for RejectErr {
+        fn log_message(&self) -> Cow<'_, str> {
+            self.0.to_string().into()
+        }
+
+        fn http_code(&self) -> StatusCode {
+            self.0
+        }
+
+        #[cfg(feature = "extra-error")]
+        type ExtraMessage = String;
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Self::ExtraMessage {
+            String::new()
+        }
+    }
+
+    fn reject_status<T>(
+        result: Result<T, RespResult<crate::Nil, RejectErr>>,
+    ) -> Option<StatusCode> {
+        match result {
+            Ok(_) => None,
+            Err(RespResult::Err(RejectErr(status))) => Some(status),
+            Err(RespResult::Success(_)) => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reject_bytes() {
+        use axum::body::Bytes;
+
+        let req = Request::new(vec![0u8; 3 * 1024 * 1024].into());
+        let ret = MapReject::<Bytes, RejectErr>::from_request(req, &()).await;
+        assert_eq!(reject_status(ret), Some(StatusCode::PAYLOAD_TOO_LARGE));
+
+        let req = Request::new("abc".into());
+        let MapReject(bytes) = MapReject::<Bytes, RejectErr>::from_request(req, &())
+            .await
+            .unwrap();
+        assert_eq!(bytes, "abc");
+    }
+
+    #[tokio::test]
+    async fn test_reject_string() {
+        let req = Request::new(vec![0xffu8, 0xfe].into());
+        let ret = MapReject::<String, RejectErr>::from_request(req, &()).await;
+        assert_eq!(reject_status(ret), Some(StatusCode::BAD_REQUEST));
+    }
+
+    #[tokio::test]
+    async fn test_reject_raw_form() {
+        use axum::extract::RawForm;
+
+        let req = Request::builder()
+            .method("POST")
+            .body("a=1".into())
+            .unwrap();
+        let ret = MapReject::<RawForm, RejectErr>::from_request(req, &()).await;
+        assert_eq!(reject_status(ret), Some(StatusCode::UNSUPPORTED_MEDIA_TYPE));
+    }
+
+    #[tokio::test]
+    async fn test_reject_raw_query_and_original_uri() {
+        use axum::extract::{OriginalUri, RawQuery};
+
+        let (mut parts, _) = Request::builder()
+            .uri("/foo?a=1")
+            .body(())
+            .unwrap()
+            .into_parts();
+        let MapReject(query) =
+            MapReject::<RawQuery, RejectErr>::from_request_parts(&mut parts, &())
+                .await
+                .unwrap();
+        assert_eq!(query.as_deref(), Some("a=1"));
+
+        let MapReject(uri) =
+            MapReject::<OriginalUri, RejectErr>::from_request_parts(&mut parts, &())
+                .await
+                .unwrap();
+        assert_eq!(uri.path(), "/foo");
+    }
+
+    #[tokio::test]
+    async fn test_reject_connect_info() {
+        use axum::extract::ConnectInfo;
+        use std::net::SocketAddr;
+
+        let (mut parts, _) = Request::new(()).into_parts();
+        let ret =
+            MapReject::<ConnectInfo<SocketAddr>, RejectErr>::from_request_parts(&mut parts, &())
+                .await;
+        assert_eq!(reject_status(ret), Some(StatusCode::INTERNAL_SERVER_ERROR));
+    }
+
+    #[tokio::test]
+    async fn test_reject_matched_path() {
+        use axum::extract::MatchedPath;
+
+        let (mut parts, _) = Request::new(()).into_parts();
+        let ret = MapReject::<MatchedPath, RejectErr>::from_request_parts(&mut parts, &()).await;
+        assert_eq!(reject_status(ret), Some(StatusCode::INTERNAL_SERVER_ERROR));
+    }
+
+    #[tokio::test]
+    async fn test_reject_nested_path() {
+        use axum::extract::NestedPath;
+
+        let (mut parts, _) = Request::new(()).into_parts();
+        let ret = MapReject::<NestedPath, RejectErr>::from_request_parts(&mut parts, &()).await;
+        assert_eq!(reject_status(ret), Some(StatusCode::INTERNAL_SERVER_ERROR));
+    }
+
+    #[cfg(feature = "multipart")]
+    #[tokio::test]
+    async fn test_reject_multipart() {
+        use axum::extract::Multipart;
+
+        let req = Request::new("".into());
+        let ret = MapReject::<Multipart, RejectErr>::from_request(req, &()).await;
+        assert_eq!(reject_status(ret), Some(StatusCode::BAD_REQUEST));
+    }
+
+    #[cfg(feature = "ws")]
+    #[tokio::test]
+    async fn test_reject_websocket_upgrade() {
+        use axum::extract::WebSocketUpgrade;
+
+        let (mut parts, _) = Request::builder()
+            .method("POST")
+            .body(())
+            .unwrap()
+            .into_parts();
+        let ret =
+            MapReject::<WebSocketUpgrade, RejectErr>::from_request_parts(&mut parts, &()).await;
+        assert_eq!(reject_status(ret), Some(StatusCode::METHOD_NOT_ALLOWED));
+    }
+
+    #[cfg(feature = "typed-header")]
+    #[tokio::test]
+    async fn test_reject_typed_header() {
+        use axum_extra::{headers::UserAgent, TypedHeader};
+
+        let (mut parts, _) = Request::new(()).into_parts();
+        let ret =
+            MapReject::<TypedHeader<UserAgent>, RejectErr>::from_request_parts(&mut parts, &())
+                .await;
+        assert_eq!(reject_status(ret), Some(StatusCode::BAD_REQUEST));
+
+        let (mut parts, _) = Request::builder()
+            .header("user-agent", "test")
+            .body(())
+            .unwrap()
+            .into_parts();
+        let MapReject(agent) =
+            MapReject::<TypedHeader<UserAgent>, RejectErr>::from_request_parts(&mut parts, &())
+                .await
+                .unwrap();
+        assert_eq!(agent.as_str(), "test");
+    }
 }
@@ -1,4 +1,33 @@
+use std::marker::PhantomData;
+
+#[cfg(feature = "actix-web")]
+mod actix;
+#[cfg(feature = "axum")]
 mod axum;
+mod page;
+#[cfg(feature = "poem")]
+mod poem;
+#[cfg(feature = "axum")]
+mod rejection;
+#[cfg(any(feature = "actix-web", feature = "salvo"))]
+mod resp_rejection;
+#[cfg(feature = "salvo")]
+mod salvo;
+mod validate;
+
+pub use self::page::PageQuery;
+#[cfg(feature = "axum")]
+pub use self::rejection::{AxumMapReject, AxumRejection, AxumValid};
+#[cfg(any(feature = "actix-web", feature = "salvo"))]
+pub use self::resp_rejection::RespRejection;
+#[cfg(feature = "salvo")]
+pub use self::salvo::SalvoPayload;
+#[cfg(feature = "garde")]
+pub use self::validate::ByGarde;
+#[cfg(feature = "validator")]
+pub use self::validate::ByValidator;
+pub use self::validate::{ByValidate, Validate, Validator};
+
 pub trait ToInner {
     type Inner;
     fn to_inner(self) -> Self::Inner;
@@ -16,3 +45,35 @@ where
 }
 
 pub struct MapReject<T: FromRequestFamily<E>, E>(pub <T::Payload as ToInner>::Inner);
+
+/// similar to [`MapReject`], but the extracted data will be validated by `V`
+///
+/// - the rejection of the extractor will be converted into `E`
+/// - the [`ValidationErrors`](crate::ValidationErrors) of validating will be converted into `E`
+///
+/// the second field is only the marker of `V`, using `Valid(data, ..)` to get the data
+///
+/// # Example
+///
+/// ```rust ignore
+/// async fn create_user(
+///     Valid(user, ..): Valid<Json<NewUser>, MyError>,
+/// ) -> RespResult<User, MyError> {
+///     // ...
+/// }
+/// ```
+pub struct Valid<T, E, V = ByValidate>(
+    pub <T::Payload as ToInner>::Inner,
+    pub PhantomData<fn() -> V>,
+)
+where
+    T: FromRequestFamily<E>;
+
+impl<T, E, V> Valid<T, E, V>
+where
+    T: FromRequestFamily<E>,
+{
+    pub(crate) fn new(data: <T::Payload as ToInner>::Inner) -> Self {
+        Self(data, PhantomData)
+    }
+}
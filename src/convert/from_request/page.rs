@@ -0,0 +1,121 @@
+use serde::Deserialize;
+
+use crate::ValidationErrors;
+
+use super::Validate;
+
+/// the pagination parameters of a list request, usually extracted from the query string
+///
+/// - `page`: the page number, start from `1`, default `1`
+/// - `per_page`: the max number of items in a page, in `1..=100`, default `20`
+/// - `cursor`: the cursor of the page, not empty if present
+///
+/// it implements [`Validate`], using with [`Valid`](crate::Valid) for rejecting the invalid parameters
+///
+/// ```rust ignore
+/// async fn list_users(
+///     Valid(query, ..): Valid<Query<PageQuery>, PlainError>,
+/// ) -> PlainRResult<Paged<User>> {
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct PageQuery {
+    pub page: u64,
+    pub per_page: u64,
+    pub cursor: Option<String>,
+}
+
+impl PageQuery {
+    /// the default number of items in a page
+    pub const DEFAULT_PER_PAGE: u64 = 20;
+    /// the max number of items in a page
+    pub const MAX_PER_PAGE: u64 = 100;
+
+    /// the number of items before this page
+    pub fn offset(&self) -> u64 {
+        self.page.saturating_sub(1).saturating_mul(self.per_page)
+    }
+
+    /// the max number of items in this page
+    pub fn limit(&self) -> u64 {
+        self.per_page
+    }
+}
+
+impl Default for PageQuery {
+    fn default() -> Self {
+        Self {
+            page: 1,
+            per_page: Self::DEFAULT_PER_PAGE,
+            cursor: None,
+        }
+    }
+}
+
+impl Validate for PageQuery {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if self.page == 0 {
+            errors.add("page", "range", "page must be at least 1");
+        }
+        if !(1..=Self::MAX_PER_PAGE).contains(&self.per_page) {
+            errors.add(
+                "per_page",
+                "range",
+                format!("per_page must be between 1 and {}", Self::MAX_PER_PAGE),
+            );
+        }
+        if self.cursor.as_deref() == Some("") {
+            errors.add("cursor", "empty", "cursor must not be empty");
+        }
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Validate;
+
+    use super::PageQuery;
+
+    #[test]
+    fn test_validate() {
+        let query = serde_json::from_str::<PageQuery>(r#"{"page": 3}"#).unwrap();
+        assert_eq!(query.per_page, PageQuery::DEFAULT_PER_PAGE);
+        assert_eq!(query.offset(), 40);
+        assert!(query.validate().is_ok());
+
+        let query =
+            serde_json::from_str::<PageQuery>(r#"{"page": 0, "per_page": 101, "cursor": ""}"#)
+                .unwrap();
+        let errors = query.validate().unwrap_err();
+        let fields = errors
+            .details()
+            .iter()
+            .map(|detail| detail.field())
+            .collect::<Vec<_>>();
+        assert_eq!(fields, [Some("page"), Some("per_page"), Some("cursor")]);
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test]
+    async fn test_valid_query() {
+        use axum::extract::{FromRequestParts, Query, Request};
+
+        use crate::{AxumValid, RespError, RespResult};
+
+        let (mut parts, _) = Request::builder()
+            .uri("/users?page=2&per_page=500")
+            .body(())
+            .unwrap()
+            .into_parts();
+        let Err(RespResult::Err(err)) =
+            AxumValid::<Query<PageQuery>>::from_request_parts(&mut parts, &()).await
+        else {
+            panic!("expect rejection")
+        };
+        assert_eq!(err.resp_details().unwrap()[0].field(), Some("per_page"));
+    }
+}
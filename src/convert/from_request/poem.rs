@@ -0,0 +1,235 @@
+use std::borrow::Cow;
+
+use futures::FutureExt;
+use http::StatusCode;
+use poem::{FromRequest, Request, RequestBody};
+
+use crate::{
+    resp_result::to_response::poem::poem_response, Nil, RespError, RespResult, ValidationErrors,
+};
+
+use super::{FromRequestFamily, MapReject, ToInner, Valid, Validator};
+
+/// convert the rejection `err` into the [`poem::Error`] responding the [`RespResult`] envelope of `E`
+fn reject<E: RespError>(err: impl Into<E>) -> poem::Error {
+    poem::Error::from_response(poem_response(RespResult::<Nil, E>::Err(err.into())))
+}
+
+/// the rejections of poem extractors, the response message is sanitized by the status code
+impl RespError for poem::Error {
+    fn log_message(&self) -> Cow<'_, str> {
+        self.to_string().into()
+    }
+
+    fn resp_message(&self) -> Cow<'_, str> {
+        let status = self.http_code();
+        match status.canonical_reason() {
+            Some(reason) if status.is_client_error() => reason.into(),
+            _ => "Internal Server Error".into(),
+        }
+    }
+
+    fn http_code(&self) -> StatusCode {
+        self.status()
+    }
+
+    #[cfg(feature = "extra-error")]
+    type ExtraMessage = Nil;
+
+    #[cfg(feature = "extra-error")]
+    fn extra_message(&self) -> Self::ExtraMessage {
+        Nil
+    }
+}
+
+impl<'a, T, E> FromRequest<'a> for MapReject<T, E>
+where
+    E: From<poem::Error> + RespError + Send,
+    T: FromRequestFamily<E>,
+    T::Payload: FromRequest<'a>,
+{
+    async fn from_request(req: &'a Request, body: &mut RequestBody) -> poem::Result<Self> {
+        // the boxing works around https://github.com/rust-lang/rust/issues/100013
+        <T::Payload as FromRequest>::from_request(req, body)
+            .boxed()
+            .await
+            .map_err(reject::<E>)
+            .map(|data| Self(data.to_inner()))
+    }
+}
+
+impl<'a, T, E, V> FromRequest<'a> for Valid<T, E, V>
+where
+    E: From<poem::Error> + From<ValidationErrors> + RespError + Send,
+    T: FromRequestFamily<E>,
+    T::Payload: FromRequest<'a>,
+    V: Validator<<T::Payload as ToInner>::Inner>,
+{
+    async fn from_request(req: &'a Request, body: &mut RequestBody) -> poem::Result<Self> {
+        let data = <T::Payload as FromRequest>::from_request(req, body)
+            .boxed()
+            .await
+            .map_err(reject::<E>)?
+            .to_inner();
+        V::validate(&data).map_err(reject::<E>)?;
+        Ok(Self::new(data))
+    }
+}
+
+mod from_request_families {
+    use poem::web::{Data, Form, Json, Path, Query};
+
+    use crate::convert::from_request::ToInner;
+
+    impl<T> ToInner for Json<T> {
+        type Inner = T;
+
+        fn to_inner(self) -> Self::Inner {
+            self.0
+        }
+    }
+
+    impl<T> ToInner for Path<T> {
+        type Inner = T;
+
+        fn to_inner(self) -> Self::Inner {
+            self.0
+        }
+    }
+
+    impl<T> ToInner for Query<T> {
+        type Inner = T;
+
+        fn to_inner(self) -> Self::Inner {
+            self.0
+        }
+    }
+
+    impl<T> ToInner for Form<T> {
+        type Inner = T;
+
+        fn to_inner(self) -> Self::Inner {
+            self.0
+        }
+    }
+
+    impl<T> ToInner for Data<T> {
+        type Inner = T;
+
+        fn to_inner(self) -> Self::Inner {
+            self.0
+        }
+    }
+}
+
+#[cfg(all(test, feature = "poem-test"))]
+mod test {
+    use std::borrow::Cow;
+
+    use http::StatusCode;
+    use poem::{handler, post, test::TestClient, web::Json, Route};
+    use serde::{Deserialize, Serialize};
+
+    use crate::{MapReject, RespError, RespResult, Valid, Validate, ValidationErrors};
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Input {
+        num: i32,
+    }
+
+    impl Validate for Input {
+        fn validate(&self) -> Result<(), ValidationErrors> {
+            let mut errors = ValidationErrors::new();
+            if self.num < 0 {
+                errors.add("num", "range", "num must not be negative");
+            }
+            errors.into_result()
+        }
+    }
+
+    #[derive(Debug)]
+    enum InputError {
+        Reject(poem::Error),
+        Invalid(ValidationErrors),
+    }
+
+    impl From<poem::Error> for InputError {
+        fn from(err: poem::Error) -> Self {
+            Self::Reject(err)
+        }
+    }
+
+    impl From<ValidationErrors> for InputError {
+        fn from(err: ValidationErrors) -> Self {
+            Self::Invalid(err)
+        }
+    }
+
+    impl RespError for InputError {
+        fn log_message(&self) -> Cow<'_, str> {
+            match self {
+                InputError::Reject(err) => err.log_message(),
+                InputError::Invalid(err) => err.log_message(),
+            }
+        }
+
+        fn http_code(&self) -> StatusCode {
+            match self {
+                InputError::Reject(err) => err.http_code(),
+                InputError::Invalid(err) => err.http_code(),
+            }
+        }
+
+        #[cfg(feature = "extra-error")]
+        type ExtraMessage = crate::Nil;
+
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Self::ExtraMessage {
+            crate::Nil
+        }
+    }
+
+    #[handler]
+    fn map_reject(input: MapReject<Json<Input>, poem::Error>) -> RespResult<Input, poem::Error> {
+        RespResult::Success(input.0)
+    }
+
+    #[handler]
+    fn valid(input: Valid<Json<Input>, InputError>) -> RespResult<Input, InputError> {
+        RespResult::Success(input.0)
+    }
+
+    #[tokio::test]
+    async fn test_map_reject() {
+        let cli = TestClient::new(Route::new().at("/", post(map_reject)));
+
+        let resp = cli.post("/").body_json(&Input { num: 1 }).send().await;
+        resp.assert_status_is_ok();
+
+        // without `Content-Type: application/json`
+        let resp = cli.post("/").body("abc").send().await;
+        resp.assert_status(StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        resp.assert_content_type("application/json");
+        let body = resp.0.into_body().into_string().await.unwrap();
+        assert!(body.contains("Unsupported Media Type"));
+
+        let resp = cli
+            .post("/")
+            .content_type("application/json")
+            .body("abc")
+            .send()
+            .await;
+        resp.assert_status(StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_valid() {
+        let cli = TestClient::new(Route::new().at("/", post(valid)));
+
+        let resp = cli.post("/").body_json(&Input { num: -1 }).send().await;
+        resp.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+
+        let resp = cli.post("/").body("abc").send().await;
+        resp.assert_status(StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+}
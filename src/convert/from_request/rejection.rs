@@ -0,0 +1,212 @@
+use std::borrow::Cow;
+
+use axum::extract::rejection::{
+    BytesRejection, ExtensionRejection, FormRejection, JsonRejection, MatchedPathRejection,
+    NestedPathRejection, PathRejection, QueryRejection, RawFormRejection, StringRejection,
+};
+use http::StatusCode;
+
+use crate::{ErrorDetail, RespError, ValidationErrors};
+
+use super::{ByValidate, MapReject, Valid};
+
+/// the [`MapReject`] using [`AxumRejection`] as the rejection
+pub type AxumMapReject<T> = MapReject<T, AxumRejection>;
+
+/// the [`Valid`] using [`AxumRejection`] as the rejection
+pub type AxumValid<T, V = ByValidate> = Valid<T, AxumRejection, V>;
+
+/// the [`RespError`] of the rejections of axum extractors
+///
+/// - the status code follows the rejection, like `400`, `415` or `422`,
+///   and the rejections caused by the server (like missing extension) will be `500`
+/// - the response message is sanitized, the detail of the rejection only appears in [`RespError::log_message`]
+///
+/// it can be composed into user error with `#[from]` and `#[resp_result(transparent)]`
+///
+/// ```rust
+/// use axum_resp_result::{AxumRejection, RespError};
+///
+/// #[derive(Debug, thiserror::Error, RespError)]
+/// pub enum PlainError {
+///     #[error(transparent)]
+///     #[resp_result(transparent)]
+///     Rejection(#[from] AxumRejection),
+///     #[error("User Not Found")]
+///     #[resp_result(err_code = 404)]
+///     NotFound,
+/// }
+/// ```
+#[derive(Debug, thiserror::Error)]
+pub enum AxumRejection {
+    #[error("Json Rejection: {0}")]
+    Json(#[from] JsonRejection),
+    #[error("Query Rejection: {0}")]
+    Query(#[from] QueryRejection),
+    #[error("Path Rejection: {0}")]
+    Path(#[from] PathRejection),
+    #[error("Form Rejection: {0}")]
+    Form(#[from] FormRejection),
+    #[error("Raw Form Rejection: {0}")]
+    RawForm(#[from] RawFormRejection),
+    #[error("Bytes Rejection: {0}")]
+    Bytes(#[from] BytesRejection),
+    #[error("String Rejection: {0}")]
+    String(#[from] StringRejection),
+    #[error("Extension Rejection: {0}")]
+    Extension(#[from] ExtensionRejection),
+    #[error("Matched Path Rejection: {0}")]
+    MatchedPath(#[from] MatchedPathRejection),
+    #[error("Nested Path Rejection: {0}")]
+    NestedPath(#[from] NestedPathRejection),
+    #[cfg(feature = "multipart")]
+    #[error("Multipart Rejection: {0}")]
+    Multipart(#[from] axum::extract::multipart::MultipartRejection),
+    #[cfg(feature = "ws")]
+    #[error("WebSocket Upgrade Rejection: {0}")]
+    WebSocketUpgrade(#[from] axum::extract::ws::rejection::WebSocketUpgradeRejection),
+    #[cfg(feature = "typed-header")]
+    #[error("Typed Header Rejection: {0}")]
+    TypedHeader(#[from] axum_extra::typed_header::TypedHeaderRejection),
+    #[error(transparent)]
+    Validation(#[from] ValidationErrors),
+}
+
+impl From<std::convert::Infallible> for AxumRejection {
+    fn from(err: std::convert::Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl AxumRejection {
+    /// the part of request which is rejected
+    fn part(&self) -> &'static str {
+        match self {
+            AxumRejection::Json(_) => "JSON Body",
+            AxumRejection::Query(_) => "Query String",
+            AxumRejection::Path(_) => "Path Parameters",
+            AxumRejection::Form(_) | AxumRejection::RawForm(_) => "Form",
+            AxumRejection::Bytes(_) | AxumRejection::String(_) => "Request Body",
+            AxumRejection::Extension(_)
+            | AxumRejection::MatchedPath(_)
+            | AxumRejection::NestedPath(_) => "Request",
+            #[cfg(feature = "multipart")]
+            AxumRejection::Multipart(_) => "Multipart Body",
+            #[cfg(feature = "ws")]
+            AxumRejection::WebSocketUpgrade(_) => "WebSocket Upgrade",
+            #[cfg(feature = "typed-header")]
+            AxumRejection::TypedHeader(_) => "Header",
+            AxumRejection::Validation(_) => "Input",
+        }
+    }
+}
+
+impl RespError for AxumRejection {
+    fn log_message(&self) -> Cow<'_, str> {
+        self.to_string().into()
+    }
+
+    fn resp_message(&self) -> Cow<'_, str> {
+        if let AxumRejection::Validation(err) = self {
+            return err.resp_message();
+        }
+        let status = self.http_code();
+        match status {
+            StatusCode::UNSUPPORTED_MEDIA_TYPE => "Unsupported Content Type".into(),
+            StatusCode::PAYLOAD_TOO_LARGE => "Payload Too Large".into(),
+            StatusCode::METHOD_NOT_ALLOWED => "Method Not Allowed".into(),
+            StatusCode::UNPROCESSABLE_ENTITY => format!("Unprocessable {}", self.part()).into(),
+            status if status.is_client_error() => format!("Invalid {}", self.part()).into(),
+            _ => "Internal Server Error".into(),
+        }
+    }
+
+    fn http_code(&self) -> StatusCode {
+        match self {
+            AxumRejection::Json(err) => err.status(),
+            AxumRejection::Query(err) => err.status(),
+            AxumRejection::Path(err) => err.status(),
+            AxumRejection::Form(err) => err.status(),
+            AxumRejection::RawForm(err) => err.status(),
+            AxumRejection::Bytes(err) => err.status(),
+            AxumRejection::String(err) => err.status(),
+            AxumRejection::Extension(err) => err.status(),
+            AxumRejection::MatchedPath(err) => err.status(),
+            AxumRejection::NestedPath(err) => err.status(),
+            #[cfg(feature = "multipart")]
+            AxumRejection::Multipart(err) => err.status(),
+            #[cfg(feature = "ws")]
+            AxumRejection::WebSocketUpgrade(err) => err.status(),
+            #[cfg(feature = "typed-header")]
+            AxumRejection::TypedHeader(_) => StatusCode::BAD_REQUEST,
+            AxumRejection::Validation(err) => err.http_code(),
+        }
+    }
+
+    fn resp_details(&self) -> Option<Cow<'_, [ErrorDetail]>> {
+        match self {
+            AxumRejection::Validation(err) => err.resp_details(),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "extra-error")]
+    type ExtraMessage = crate::Nil;
+
+    #[cfg(feature = "extra-error")]
+    fn extra_message(&self) -> Self::ExtraMessage {
+        crate::Nil
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use axum::extract::{FromRequest, FromRequestParts, Json, Path, Request};
+    use http::StatusCode;
+    use serde::Deserialize;
+
+    use crate::{RespError, RespResult};
+
+    use super::AxumMapReject;
+
+    #[derive(Debug, Deserialize)]
+    struct Input {
+        #[allow(dead_code)]
+        num: i32,
+    }
+
+    #[tokio::test]
+    async fn test_json_rejection() {
+        let req = Request::new(r#"{"num":1}"#.into());
+        let Err(RespResult::Err(err)) = AxumMapReject::<Json<Input>>::from_request(req, &()).await
+        else {
+            panic!("expect rejection")
+        };
+        assert_eq!(err.http_code(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        assert_eq!(err.resp_message(), "Unsupported Content Type");
+
+        let req = Request::builder()
+            .header("content-type", "application/json")
+            .body(r#"{"num":"abc"}"#.into())
+            .unwrap();
+        let Err(RespResult::Err(err)) = AxumMapReject::<Json<Input>>::from_request(req, &()).await
+        else {
+            panic!("expect rejection")
+        };
+        assert_eq!(err.http_code(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(err.resp_message(), "Unprocessable JSON Body");
+        assert!(err.log_message().starts_with("Json Rejection"));
+    }
+
+    #[tokio::test]
+    async fn test_path_rejection() {
+        let (mut parts, _) = Request::new(()).into_parts();
+        let Err(RespResult::Err(err)) =
+            AxumMapReject::<Path<Input>>::from_request_parts(&mut parts, &()).await
+        else {
+            panic!("expect rejection")
+        };
+        assert_eq!(err.http_code(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(err.resp_message(), "Internal Server Error");
+    }
+}
@@ -0,0 +1,33 @@
+use std::fmt::{Debug, Display};
+
+use crate::RespError;
+
+/// the rejection of [`MapReject`](crate::MapReject) and [`Valid`](crate::Valid)
+/// in actix-web and salvo, it will respond the [`RespResult`](crate::RespResult) envelope of `E`
+#[cfg_attr(docsrs, doc(cfg(any(feature = "actix-web", feature = "salvo"))))]
+pub struct RespRejection<E>(pub(crate) E);
+
+impl<E> RespRejection<E> {
+    pub(crate) fn new(err: impl Into<E>) -> Self {
+        Self(err.into())
+    }
+
+    /// get the inner error
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+impl<E: RespError> Debug for RespRejection<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RespRejection")
+            .field(&self.0.log_message())
+            .finish()
+    }
+}
+
+impl<E: RespError> Display for RespRejection<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.log_message())
+    }
+}
@@ -0,0 +1,265 @@
+use std::{borrow::Cow, future::Future};
+
+use http::StatusCode;
+use salvo::{
+    extract::{FormBody, JsonBody, Metadata},
+    http::ParseError,
+    Depot, Extractible, Request, Response, Scribe,
+};
+use serde::Deserialize;
+
+use crate::{Nil, RespError, RespResult, ValidationErrors};
+
+use super::{FromRequestFamily, MapReject, RespRejection, ToInner, Valid, Validator};
+
+/// the salvo extractors can be used by [`MapReject`] and [`Valid`],
+/// the rejection of them is [`ParseError`]
+#[cfg_attr(docsrs, doc(cfg(feature = "salvo")))]
+pub trait SalvoPayload<'ex>: Extractible<'ex> + Sized {
+    /// extract the payload from `req`
+    fn extract_payload(
+        req: &'ex mut Request,
+    ) -> impl Future<Output = Result<Self, ParseError>> + Send;
+}
+
+impl<'ex, T> SalvoPayload<'ex> for JsonBody<T>
+where
+    T: Deserialize<'ex> + Send,
+{
+    async fn extract_payload(req: &'ex mut Request) -> Result<Self, ParseError> {
+        req.parse_json().await.map(JsonBody)
+    }
+}
+
+impl<'ex, T> SalvoPayload<'ex> for FormBody<T>
+where
+    T: Deserialize<'ex> + Send,
+{
+    async fn extract_payload(req: &'ex mut Request) -> Result<Self, ParseError> {
+        req.parse_form().await.map(FormBody)
+    }
+}
+
+impl<E: RespError> Scribe for RespRejection<E> {
+    fn render(self, res: &mut Response) {
+        RespResult::<Nil, E>::Err(self.0).render(res)
+    }
+}
+
+/// the rejections of salvo extractors, the response message is sanitized by the status code
+impl RespError for ParseError {
+    fn log_message(&self) -> Cow<'_, str> {
+        self.to_string().into()
+    }
+
+    fn resp_message(&self) -> Cow<'_, str> {
+        self.http_code()
+            .canonical_reason()
+            .unwrap_or("Bad Request")
+            .into()
+    }
+
+    fn http_code(&self) -> StatusCode {
+        match self {
+            ParseError::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            ParseError::InvalidContentType | ParseError::NotMultipart | ParseError::NotFormData => {
+                StatusCode::UNSUPPORTED_MEDIA_TYPE
+            }
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    #[cfg(feature = "extra-error")]
+    type ExtraMessage = crate::Nil;
+
+    #[cfg(feature = "extra-error")]
+    fn extra_message(&self) -> Self::ExtraMessage {
+        crate::Nil
+    }
+}
+
+impl<'ex, T, E> Extractible<'ex> for MapReject<T, E>
+where
+    E: From<ParseError> + RespError + Send + 'static,
+    T: FromRequestFamily<E>,
+    T::Payload: SalvoPayload<'ex> + Send,
+{
+    fn metadata() -> &'static Metadata {
+        <T::Payload as Extractible>::metadata()
+    }
+
+    #[allow(refining_impl_trait)]
+    async fn extract(req: &'ex mut Request, _: &'ex mut Depot) -> Result<Self, RespRejection<E>> {
+        <T::Payload as SalvoPayload>::extract_payload(req)
+            .await
+            .map_err(RespRejection::<E>::new)
+            .map(|data| Self(data.to_inner()))
+    }
+}
+
+impl<'ex, T, E, V> Extractible<'ex> for Valid<T, E, V>
+where
+    E: From<ParseError> + From<ValidationErrors> + RespError + Send + 'static,
+    T: FromRequestFamily<E>,
+    T::Payload: SalvoPayload<'ex> + Send,
+    V: Validator<<T::Payload as ToInner>::Inner>,
+{
+    fn metadata() -> &'static Metadata {
+        <T::Payload as Extractible>::metadata()
+    }
+
+    #[allow(refining_impl_trait)]
+    async fn extract(req: &'ex mut Request, _: &'ex mut Depot) -> Result<Self, RespRejection<E>> {
+        let data = <T::Payload as SalvoPayload>::extract_payload(req)
+            .await
+            .map_err(RespRejection::<E>::new)?
+            .to_inner();
+        V::validate(&data).map_err(RespRejection::<E>::new)?;
+        Ok(Self::new(data))
+    }
+}
+
+mod from_request_families {
+    use salvo::extract::{FormBody, JsonBody};
+
+    use crate::convert::from_request::ToInner;
+
+    impl<T> ToInner for JsonBody<T> {
+        type Inner = T;
+
+        fn to_inner(self) -> Self::Inner {
+            self.0
+        }
+    }
+
+    impl<T> ToInner for FormBody<T> {
+        type Inner = T;
+
+        fn to_inner(self) -> Self::Inner {
+            self.0
+        }
+    }
+}
+
+#[cfg(all(test, feature = "salvo-test"))]
+mod test {
+    use std::borrow::Cow;
+
+    use http::StatusCode;
+    use salvo::{
+        extract::JsonBody,
+        handler,
+        http::ParseError,
+        test::{ResponseExt, TestClient},
+        Router, Service, Writer,
+    };
+    use serde::Deserialize;
+
+    use crate::{MapReject, RespError, RespResult, Valid, Validate, ValidationErrors};
+
+    #[derive(Debug, Deserialize, serde::Serialize)]
+    struct Input {
+        num: i32,
+    }
+
+    impl Validate for Input {
+        fn validate(&self) -> Result<(), ValidationErrors> {
+            let mut errors = ValidationErrors::new();
+            if self.num < 0 {
+                errors.add("num", "range", "num must not be negative");
+            }
+            errors.into_result()
+        }
+    }
+
+    #[derive(Debug)]
+    enum InputError {
+        Reject(ParseError),
+        Invalid(ValidationErrors),
+    }
+
+    impl From<ParseError> for InputError {
+        fn from(err: ParseError) -> Self {
+            Self::Reject(err)
+        }
+    }
+
+    impl From<ValidationErrors> for InputError {
+        fn from(err: ValidationErrors) -> Self {
+            Self::Invalid(err)
+        }
+    }
+
+    impl RespError for InputError {
+        fn log_message(&self) -> Cow<'_, str> {
+            match self {
+                InputError::Reject(err) => err.log_message(),
+                InputError::Invalid(err) => err.log_message(),
+            }
+        }
+
+        fn http_code(&self) -> StatusCode {
+            match self {
+                InputError::Reject(err) => err.http_code(),
+                InputError::Invalid(err) => err.http_code(),
+            }
+        }
+
+        #[cfg(feature = "extra-error")]
+        type ExtraMessage = crate::Nil;
+
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Self::ExtraMessage {
+            crate::Nil
+        }
+    }
+
+    #[handler]
+    async fn map_reject(
+        input: MapReject<JsonBody<Input>, ParseError>,
+    ) -> RespResult<Input, ParseError> {
+        RespResult::Success(input.0)
+    }
+
+    #[handler]
+    async fn valid(input: Valid<JsonBody<Input>, InputError>) -> RespResult<Input, InputError> {
+        RespResult::Success(input.0)
+    }
+
+    #[tokio::test]
+    async fn test_map_reject() {
+        let service = Service::new(Router::new().post(map_reject));
+
+        let resp = TestClient::post("http://127.0.0.1/")
+            .json(&Input { num: 1 })
+            .send(&service)
+            .await;
+        assert_eq!(resp.status_code, Some(StatusCode::OK));
+
+        // without `Content-Type: application/json`
+        let mut resp = TestClient::post("http://127.0.0.1/")
+            .text("abc")
+            .send(&service)
+            .await;
+        assert_eq!(resp.status_code, Some(StatusCode::UNSUPPORTED_MEDIA_TYPE));
+        let body = resp.take_string().await.unwrap();
+        assert!(body.contains("Unsupported Media Type"));
+    }
+
+    #[tokio::test]
+    async fn test_valid() {
+        let service = Service::new(Router::new().post(valid));
+
+        let resp = TestClient::post("http://127.0.0.1/")
+            .json(&Input { num: -1 })
+            .send(&service)
+            .await;
+        assert_eq!(resp.status_code, Some(StatusCode::UNPROCESSABLE_ENTITY));
+
+        let resp = TestClient::post("http://127.0.0.1/")
+            .text("abc")
+            .send(&service)
+            .await;
+        assert_eq!(resp.status_code, Some(StatusCode::UNSUPPORTED_MEDIA_TYPE));
+    }
+}
@@ -0,0 +1,133 @@
+use crate::ValidationErrors;
+
+/// the data can be validated after extracted by [`Valid`](crate::Valid)
+pub trait Validate {
+    /// validate the data
+    /// - `Ok(())` if the data is valid
+    /// - `Err(_)` with all the errors otherwise
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
+
+/// the strategy of validating the data `T`, using by [`Valid`](crate::Valid)
+pub trait Validator<T> {
+    /// validate the `data`
+    fn validate(data: &T) -> Result<(), ValidationErrors>;
+}
+
+/// validate the data by [`Validate`]
+pub struct ByValidate;
+
+impl<T: Validate> Validator<T> for ByValidate {
+    #[inline]
+    fn validate(data: &T) -> Result<(), ValidationErrors> {
+        data.validate()
+    }
+}
+
+#[cfg(feature = "validator")]
+#[cfg_attr(docsrs, doc(cfg(feature = "validator")))]
+/// validate the data by [`validator::Validate`]
+pub struct ByValidator;
+
+#[cfg(feature = "validator")]
+impl<T: validator::Validate> Validator<T> for ByValidator {
+    #[inline]
+    fn validate(data: &T) -> Result<(), ValidationErrors> {
+        data.validate().map_err(Into::into)
+    }
+}
+
+#[cfg(feature = "validator")]
+impl From<validator::ValidationErrors> for ValidationErrors {
+    fn from(errors: validator::ValidationErrors) -> Self {
+        fn flatten(prefix: &str, errors: &validator::ValidationErrors, out: &mut ValidationErrors) {
+            use validator::ValidationErrorsKind;
+
+            for (field, kind) in errors.errors() {
+                let path = if prefix.is_empty() {
+                    field.to_string()
+                } else {
+                    format!("{prefix}.{field}")
+                };
+                match kind {
+                    ValidationErrorsKind::Field(errs) => {
+                        for err in errs {
+                            let message = err.message.clone().unwrap_or_else(|| err.code.clone());
+                            out.add(path.clone(), err.code.clone(), message);
+                        }
+                    }
+                    ValidationErrorsKind::Struct(inner) => flatten(&path, inner, out),
+                    ValidationErrorsKind::List(items) => {
+                        for (idx, inner) in items {
+                            flatten(&format!("{path}[{idx}]"), inner, out)
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut out = ValidationErrors::new();
+        flatten("", &errors, &mut out);
+        out
+    }
+}
+
+#[cfg(feature = "garde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "garde")))]
+/// validate the data by [`garde::Validate`]
+pub struct ByGarde;
+
+#[cfg(feature = "garde")]
+impl<T> Validator<T> for ByGarde
+where
+    T: garde::Validate,
+    T::Context: Default,
+{
+    #[inline]
+    fn validate(data: &T) -> Result<(), ValidationErrors> {
+        data.validate().map_err(Into::into)
+    }
+}
+
+#[cfg(feature = "garde")]
+impl From<garde::Report> for ValidationErrors {
+    fn from(report: garde::Report) -> Self {
+        let mut out = ValidationErrors::new();
+        for (path, err) in report.iter() {
+            out.add(path.to_string(), "invalid", err.message().to_string());
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "validator")]
+    #[test]
+    fn test_from_validator() {
+        use crate::ValidationErrors;
+
+        let mut errors = validator::ValidationErrors::new();
+        errors.add("email", validator::ValidationError::new("email"));
+
+        let errors = ValidationErrors::from(errors);
+        let detail = &errors.details()[0];
+        assert_eq!(detail.field(), Some("email"));
+        assert_eq!(detail.code(), "email");
+        assert_eq!(detail.message(), "email");
+    }
+
+    #[cfg(feature = "garde")]
+    #[test]
+    fn test_from_garde() {
+        use crate::ValidationErrors;
+
+        let mut report = garde::Report::new();
+        report.append(garde::Path::new("name"), garde::Error::new("too short"));
+
+        let errors = ValidationErrors::from(report);
+        let detail = &errors.details()[0];
+        assert_eq!(detail.field(), Some("name"));
+        assert_eq!(detail.message(), "too short");
+    }
+}
@@ -1,7 +1,7 @@
 pub mod from_request;
 use std::{convert::Infallible, future::Future};
 
-use crate::{RespError, RespResult};
+use crate::{NotFound, RespError, RespResult};
 
 /// convert into [`RespResult`](crate::RespResult)
 pub trait IntoRespResult<T, E: RespError> {
@@ -83,6 +83,82 @@ impl Fallible for () {
     }
 }
 
+impl<T> Fallible for Option<T> {
+    type Success = T;
+
+    type Failure = NotFound;
+
+    fn to_result(self) -> Result<Self::Success, Self::Failure> {
+        self.ok_or(NotFound)
+    }
+}
+
+/// the [`Fallible`] whose success value is an [`Option`],
+/// the `None` will be converted into the failure
+pub trait FallibleOption {
+    type Success;
+    type Failure;
+
+    fn to_result_or<N, F>(self, none: F) -> Result<Self::Success, Self::Failure>
+    where
+        N: Into<Self::Failure>,
+        F: FnOnce() -> N;
+}
+
+impl<T, R> FallibleOption for Result<Option<T>, R> {
+    type Success = T;
+
+    type Failure = R;
+
+    fn to_result_or<N, F>(self, none: F) -> Result<Self::Success, Self::Failure>
+    where
+        N: Into<Self::Failure>,
+        F: FnOnce() -> N,
+    {
+        self?.ok_or_else(|| none().into())
+    }
+}
+
+impl<T, R> FallibleOption for RespResult<Option<T>, R> {
+    type Success = T;
+
+    type Failure = R;
+
+    fn to_result_or<N, F>(self, none: F) -> Result<T, R>
+    where
+        N: Into<R>,
+        F: FnOnce() -> N,
+    {
+        self.to_result()?.ok_or_else(|| none().into())
+    }
+}
+
+/// construct the failure value from the error, using by the `?` rewritten by
+/// [`resp_result`](crate::resp_result) attribute macro
+pub trait FromFailure<E> {
+    fn from_failure(err: E) -> Self;
+}
+
+impl<T, E, F> FromFailure<E> for Result<T, F>
+where
+    F: From<E>,
+{
+    #[inline]
+    fn from_failure(err: E) -> Self {
+        Err(F::from(err))
+    }
+}
+
+impl<T, E, F> FromFailure<E> for RespResult<T, F>
+where
+    F: From<E>,
+{
+    #[inline]
+    fn from_failure(err: E) -> Self {
+        RespResult::Err(F::from(err))
+    }
+}
+
 #[inline]
 /// receive a [Future](core::future::Future) applying it immediately, then convent the result into [RespResult](crate::RespResult)
 pub async fn resp_try<Fut, T, E>(future: Fut) -> RespResult<T, E>
@@ -93,3 +169,41 @@ where
 {
     future.await.into_rresult()
 }
+
+/// annotate the output of the async block generated by `#[resp_result]`,
+/// making the `?` and `return` inside it know the return type
+#[doc(hidden)]
+#[inline]
+pub fn __async_body<T, F: Future<Output = T>>(body: F) -> F {
+    body
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Fallible, FallibleOption, NotFound, RespResult};
+
+    #[test]
+    fn test_option_fallible() {
+        assert_eq!(Some(1).to_result(), Ok(1));
+        assert_eq!(None::<i32>.to_result(), Err(NotFound));
+    }
+
+    #[test]
+    fn test_fallible_option() {
+        let none = || "none";
+
+        assert_eq!(Ok::<_, String>(Some(1)).to_result_or(none), Ok(1));
+        assert_eq!(
+            Ok::<Option<i32>, String>(None).to_result_or(none),
+            Err("none".to_string())
+        );
+        assert_eq!(
+            Err::<Option<i32>, _>("err".to_string()).to_result_or(none),
+            Err("err".to_string())
+        );
+        assert_eq!(
+            RespResult::<Option<i32>, String>::Success(None).to_result_or(none),
+            Err("none".to_string())
+        );
+    }
+}
@@ -5,6 +5,7 @@ pub(crate) trait ExpectExt<T, E> {
     where
         E: Debug;
 
+    #[allow(dead_code)]
     fn with_expect_err(self, msg: &str) -> E
     where
         T: Debug;
@@ -66,10 +67,9 @@ impl<T> ExpectExt<T, ()> for Option<T> {
         }
         #[cfg(not(feature = "tracing"))]
         {
-            match self {
-                this @ Some(_) => panic!("not None {} {this:?}", msg),
-                None => (),
-            };
+            if let this @ Some(_) = self {
+                panic!("not None {} {this:?}", msg)
+            }
         }
     }
 }
@@ -1,4 +1,4 @@
-use http::{HeaderMap, StatusCode};
+use http::{header::CONTENT_TYPE, HeaderMap, StatusCode};
 use serde::Serialize;
 
 use crate::{resp_body::RespBody, ExtraFlag, ExtraFlags, RespError, RespResult};
@@ -6,8 +6,11 @@ use crate::{resp_body::RespBody, ExtraFlag, ExtraFlags, RespError, RespResult};
 use super::flags::HeaderType;
 
 #[derive(Debug)]
+/// the effect on the response body
 pub enum BodyEffect {
+    /// the response body will be empty
     Empty,
+    /// continue serializing the response body
     Continue,
 }
 
@@ -34,7 +37,11 @@ pub trait Effects {
 impl Effects for ExtraFlags {
     #[inline]
     fn body_effect(&self, body: &mut Vec<u8>) -> BodyEffect {
-        if self.flags.iter().any(|flag| flag == &ExtraFlag::EmptyBody) {
+        if self
+            .flags
+            .iter()
+            .any(|flag| matches!(flag, ExtraFlag::EmptyBody | ExtraFlag::NoContent))
+        {
             body.clear();
             BodyEffect::Empty
         } else {
@@ -45,26 +52,21 @@ impl Effects for ExtraFlags {
     fn status_effect(&self) -> Option<StatusCode> {
         self.flags
             .iter()
-            .filter_map(|flag| {
-                if let ExtraFlag::SetStatus(status) = flag {
-                    Some(status)
-                } else {
-                    None
-                }
+            .filter_map(|flag| match flag {
+                ExtraFlag::SetStatus(status) => Some(*status),
+                ExtraFlag::NoContent => Some(StatusCode::NO_CONTENT),
+                _ => None,
             })
             .reduce(|_, r| r)
-            .copied()
     }
     #[inline]
     fn headers_effect(&self, header_map: &mut HeaderMap) {
         self.flags
             .iter()
-            .filter_map(|flag| {
-                if let ExtraFlag::RemoveHeader(k) = flag {
-                    Some(k)
-                } else {
-                    None
-                }
+            .filter_map(|flag| match flag {
+                ExtraFlag::RemoveHeader(k) => Some(k),
+                ExtraFlag::NoContent => Some(&CONTENT_TYPE),
+                _ => None,
             })
             .for_each(|k| {
                 header_map.remove(k);
@@ -1,5 +1,5 @@
 use crate::{
-    resp_body::{LoadSerde, RespBody},
+    resp_body::{EnvelopeConfig, LoadSerde, RespBody},
     ExtraFlags, RespResult,
 };
 
@@ -55,24 +55,55 @@ impl<T: LoadSerde> LoadSerde for FlagWrap<T> {
     fn load_serde(&self) -> &Self::SerdeData {
         self.inner.load_serde()
     }
+
+    #[inline]
+    fn is_unit() -> bool {
+        T::is_unit()
+    }
 }
 
-impl<T> Effects for FlagWrap<T> {
+impl<T: Effects> Effects for FlagWrap<T> {
     #[inline]
     fn body_effect(&self, body: &mut Vec<u8>) -> BodyEffect {
-        self.flags.body_effect(body)
+        match self.flags.body_effect(body) {
+            BodyEffect::Continue => self.inner.body_effect(body),
+            BodyEffect::Empty => BodyEffect::Empty,
+        }
     }
     #[inline]
     fn status_effect(&self) -> Option<http::StatusCode> {
-        self.flags.status_effect()
+        self.flags
+            .status_effect()
+            .or_else(|| self.inner.status_effect())
     }
     #[inline]
     fn headers_effect(&self, map: &mut http::HeaderMap) {
+        self.inner.headers_effect(map);
         self.flags.headers_effect(map)
     }
 }
 
-impl<T: LoadSerde> RespBody for FlagWrap<T> {}
+impl<T: RespBody> RespBody for FlagWrap<T> {
+    #[inline]
+    fn success_status(&self) -> Option<http::StatusCode> {
+        self.inner.success_status()
+    }
+    #[inline]
+    fn page_meta(&self) -> Option<&crate::PageMeta> {
+        self.inner.page_meta()
+    }
+    #[inline]
+    fn warnings(&self) -> &[crate::Warning] {
+        self.inner.warnings()
+    }
+    #[inline]
+    fn serialize_body<S>(&self, cfg: EnvelopeConfig<'_>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.inner.serialize_body(cfg, serializer)
+    }
+}
 
 #[cfg(test)]
 mod test {
@@ -1,4 +1,5 @@
 use std::{
+    any::TypeId,
     fmt::Debug,
     ops::{Add, AddAssign},
 };
@@ -18,6 +19,8 @@ pub enum ExtraFlag {
     SetHeader(HeaderName, HeaderValue, HeaderType),
     /// remove a header
     RemoveHeader(HeaderName),
+    /// respond `204 No Content` with empty body and without `Content-Type`
+    NoContent,
 }
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
 /// the action of set header
@@ -35,6 +38,12 @@ impl ExtraFlag {
         Self::EmptyBody
     }
 
+    /// create [`ExtraFlag::NoContent`] flag
+    #[inline]
+    pub const fn no_content() -> Self {
+        Self::NoContent
+    }
+
     #[inline]
     /// create [`ExtraFlag::SetStatus`] flag
     pub const fn status(status: StatusCode) -> Self {
@@ -91,7 +100,7 @@ impl ExtraFlag {
 ///
 /// ```rust
 ///
-/// use crate::{ExtraFlag,ExtraFlags};
+/// use axum_resp_result::{ExtraFlag,ExtraFlags};
 /// use http::StatusCode;
 ///
 /// let mut flags: ExtraFlags = ExtraFlag::empty_body() + ExtraFlag::status(StatusCode::BAD_REQUEST);
@@ -103,6 +112,20 @@ pub struct ExtraFlags {
     pub(crate) flags: Vec<ExtraFlag>,
 }
 
+impl ExtraFlags {
+    /// the [`ExtraFlag::EmptyBody`] flag if the body type `B` is `T`, otherwise no flag
+    ///
+    /// it is used by the `empty_body_on = T` argument of `#[resp_result]`
+    #[inline]
+    pub fn empty_body_on<T: 'static, B: 'static>() -> Self {
+        if TypeId::of::<T>() == TypeId::of::<B>() {
+            ExtraFlag::EmptyBody.into()
+        } else {
+            ().into()
+        }
+    }
+}
+
 impl From<()> for ExtraFlags {
     fn from(_: ()) -> Self {
         ExtraFlags { flags: vec![] }
@@ -145,6 +168,15 @@ impl Add<ExtraFlag> for ExtraFlags {
     }
 }
 
+impl Add for ExtraFlags {
+    type Output = ExtraFlags;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.flags.extend(rhs.flags);
+        self
+    }
+}
+
 impl AddAssign for ExtraFlags {
     fn add_assign(&mut self, rhs: Self) {
         self.flags.extend(rhs.flags);
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use super::{MessageKey, Translator};
+
+/// a simple in-memory [`Translator`]
+///
+/// the message template can contain `{name}` placeholder,
+/// which will be replaced by the argument with the same name
+///
+/// # Example
+///
+/// ```rust
+/// use axum_resp_result::{Catalog, MessageKey, Translator};
+///
+/// let catalog = Catalog::new()
+///     .with("en", "user.not_found", "user {id} not found")
+///     .with("fr", "user.not_found", "utilisateur {id} introuvable");
+///
+/// let message = MessageKey::new("user.not_found").arg("id", "42");
+/// assert_eq!(
+///     catalog.translate("fr", &message).as_deref(),
+///     Some("utilisateur 42 introuvable")
+/// );
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Catalog {
+    messages: HashMap<String, HashMap<String, String>>,
+}
+
+impl Catalog {
+    /// create an empty [`Catalog`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// adding the message `template` of `key` in `locale`
+    pub fn insert(
+        &mut self,
+        locale: impl Into<String>,
+        key: impl Into<String>,
+        template: impl Into<String>,
+    ) {
+        self.messages
+            .entry(locale.into())
+            .or_default()
+            .insert(key.into(), template.into());
+    }
+
+    /// adding the message `template` of `key` in `locale`, then return the [`Catalog`]
+    pub fn with(
+        mut self,
+        locale: impl Into<String>,
+        key: impl Into<String>,
+        template: impl Into<String>,
+    ) -> Self {
+        self.insert(locale, key, template);
+        self
+    }
+}
+
+impl Translator for Catalog {
+    fn translate(&self, locale: &str, message: &MessageKey<'_>) -> Option<String> {
+        let template = self.messages.get(locale)?.get(message.key())?;
+        let translated = message.args().fold(template.clone(), |msg, (name, value)| {
+            msg.replace(&format!("{{{name}}}"), value)
+        });
+        Some(translated)
+    }
+}
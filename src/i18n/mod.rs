@@ -0,0 +1,131 @@
+mod catalog;
+
+use std::{borrow::Cow, sync::Arc};
+
+use crate::{config::InnerSerdeConfig, context::RequestContext, resp_error::RespError};
+
+pub use self::catalog::Catalog;
+
+/// the key of response message and the arguments for formatting it
+///
+/// it is provided by [`RespError::resp_message_key`](crate::RespError::resp_message_key),
+/// and will be translated by the [`Translator`] set by [`SerdeConfig::translator`](crate::SerdeConfig::translator)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageKey<'a> {
+    key: Cow<'a, str>,
+    args: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> MessageKey<'a> {
+    /// create a new [`MessageKey`] without any argument
+    pub fn new(key: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            key: key.into(),
+            args: Vec::new(),
+        }
+    }
+
+    /// adding an argument with provide `name` and `value`
+    pub fn arg(mut self, name: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) -> Self {
+        self.args.push((name.into(), value.into()));
+        self
+    }
+
+    /// the key of the message
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// convert into the [`MessageKey`] owning all the data
+    pub fn into_owned(self) -> MessageKey<'static> {
+        MessageKey {
+            key: self.key.into_owned().into(),
+            args: self
+                .args
+                .into_iter()
+                .map(|(k, v)| (k.into_owned().into(), v.into_owned().into()))
+                .collect(),
+        }
+    }
+
+    /// the arguments of the message
+    pub fn args(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.args.iter().map(|(k, v)| (k.as_ref(), v.as_ref()))
+    }
+}
+
+/// the translation catalog of response message
+pub trait Translator: Send + Sync + 'static {
+    /// translate the `message` into provide `locale`
+    /// - return `Some(_)` if the translation is available
+    /// - return `None` otherwise
+    fn translate(&self, locale: &str, message: &MessageKey<'_>) -> Option<String>;
+}
+
+pub(crate) struct InnerI18nConfig {
+    pub(crate) translator: Arc<dyn Translator>,
+    pub(crate) fallback_locale: Option<Arc<str>>,
+}
+
+#[cfg(feature = "context")]
+/// parse the `Accept-Language` header value into a list of locale, sorted by the quality
+pub(crate) fn parse_accept_language(header: &str) -> Vec<Arc<str>> {
+    let mut locales = header
+        .split(',')
+        .filter_map(|item| {
+            let mut parts = item.split(';');
+            let locale = parts.next()?.trim();
+            let quality = parts
+                .filter_map(|param| param.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            (!locale.is_empty() && locale != "*" && quality > 0.0).then_some((locale, quality))
+        })
+        .collect::<Vec<_>>();
+    locales.sort_by(|(_, l), (_, r)| r.total_cmp(l));
+    locales
+        .into_iter()
+        .map(|(locale, _)| locale.into())
+        .collect()
+}
+
+/// get the response message of `err`, translated by the locale of current request if possible
+pub(crate) fn localized_message<'e, E: RespError>(
+    cfg: &InnerSerdeConfig,
+    err: &'e E,
+    context: Option<&RequestContext>,
+) -> Cow<'e, str> {
+    let translated = cfg.i18n.as_ref().and_then(|i18n| {
+        let message = err.resp_message_key()?;
+        let locales = context.map(RequestContext::locales).unwrap_or_default();
+
+        locales
+            .iter()
+            .map(AsRef::as_ref)
+            .flat_map(|locale| [Some(locale), locale.split_once('-').map(|(lang, _)| lang)])
+            .flatten()
+            .chain(i18n.fallback_locale.as_deref())
+            .find_map(|locale| i18n.translator.translate(locale, &message))
+    });
+
+    match translated {
+        Some(msg) => msg.into(),
+        None => err.resp_message(),
+    }
+}
+
+#[cfg(all(test, feature = "context"))]
+mod test {
+    use super::parse_accept_language;
+
+    #[test]
+    fn test_parse_accept_language() {
+        let locales = parse_accept_language("fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5");
+        let locales = locales.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        assert_eq!(locales, ["fr-CH", "fr", "en", "de"]);
+
+        let locales = parse_accept_language("en;q=0.1, zh-CN, ja;q=0");
+        let locales = locales.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        assert_eq!(locales, ["zh-CN", "en"]);
+    }
+}
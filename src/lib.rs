@@ -2,30 +2,61 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc = include_str!("../Readme.md")]
 
+#[cfg(feature = "tracing")]
+extern crate trace as tracing;
+
 mod config;
+mod context;
 mod convert;
 mod expect_ext;
 mod extra_flag;
-mod owner_leak;
+mod i18n;
 mod resp_body;
 mod resp_error;
 mod resp_result;
 
+#[cfg(feature = "axum")]
 pub use self::resp_result::to_response::axum::axum_respond_part;
 use once_cell::sync::OnceCell;
 
 use config::InnerConfig;
-pub use config::{ConfigTrait, DefaultConfig, RespConfig, SerdeConfig, SignType, StatusSign};
+pub use config::{
+    ConfigTrait, DefaultConfig, RespConfig, SerdeConfig, SignType, StatusSign, TimestampFormat,
+};
+pub use context::RequestContext;
+#[cfg(feature = "context")]
+#[cfg_attr(docsrs, doc(cfg(feature = "context")))]
+pub use context::{RespContextLayer, RespContextService};
+#[cfg(feature = "axum")]
+pub use convert::from_request::{AxumMapReject, AxumRejection, AxumValid};
 pub use convert::{
-    from_request::{FromRequestFamily, MapReject, ToInner},
+    from_request::{
+        ByValidate, FromRequestFamily, MapReject, PageQuery, ToInner, Valid, Validate, Validator,
+    },
     resp_try, IntoRespResult, IntoRespResultWithErr,
 };
 pub use extra_flag::{
+    effect::{BodyEffect, Effects},
     flag_wrap::FlagWrap,
     flags::{ExtraFlag, ExtraFlags, HeaderType},
 };
-pub use resp_error::RespError;
+pub use i18n::{Catalog, MessageKey, Translator};
+pub use resp_body::{
+    Accepted, BatchBody, BatchResp, BatchSummary, Created, LoadSerde, NoContent, PageMeta, Paged,
+    PartialContent, RespBody, Warning, WithWarnings,
+};
+#[cfg(feature = "anyhow")]
+pub use resp_error::AnyRespError;
+pub use resp_error::{
+    BoxRespError, DynRespError, ErrorDetail, NotFound, RespError, ValidationErrors,
+};
+#[cfg(feature = "async-graphql")]
+pub use resp_result::to_response::graphql::graphql_error;
+#[cfg(feature = "tonic")]
+pub use resp_result::to_response::tonic::grpc_code_from_http;
 pub use resp_result::{Nil, RespResult};
+#[cfg(feature = "tonic")]
+pub use tonic::Code as GrpcCode;
 
 pub type FlagRespResult<T, E> = RespResult<FlagWrap<T>, E>;
 
@@ -34,7 +65,7 @@ static RESP_RESULT_CONFIG: OnceCell<InnerConfig> = OnceCell::new();
 pub fn try_set_config<C: ConfigTrait>(cfg: &C) -> Result<(), SetRespResultConfigureError> {
     let inner = InnerConfig::from_cfg(cfg);
 
-    #[cfg(feature = "trace")]
+    #[cfg(feature = "tracing")]
     trace::event!(trace::Level::DEBUG, set_config = "On Going");
     RESP_RESULT_CONFIG
         .set(inner)
@@ -49,11 +80,11 @@ pub fn try_set_config<C: ConfigTrait>(cfg: &C) -> Result<(), SetRespResultConfig
 pub fn set_config<C: ConfigTrait>(cfg: &C) {
     match try_set_config(cfg) {
         Ok(_) => {
-            #[cfg(feature = "trace")]
+            #[cfg(feature = "tracing")]
             trace::event!(trace::Level::INFO, set_config = "Ready");
         }
         Err(err) => {
-            #[cfg(feature = "trace")]
+            #[cfg(feature = "tracing")]
             trace::event!(trace::Level::ERROR, set_config = "Error", error = %err);
             panic!("{}", err);
         }
@@ -62,7 +93,7 @@ pub fn set_config<C: ConfigTrait>(cfg: &C) {
 
 pub(crate) fn get_config() -> &'static InnerConfig {
     RESP_RESULT_CONFIG.get_or_init(|| {
-        #[cfg(feature = "trace")]
+        #[cfg(feature = "tracing")]
         trace::event!(
             trace::Level::WARN,
             set_config = "None",
@@ -78,5 +109,15 @@ pub struct SetRespResultConfigureError;
 pub use axum_resp_result_macro::resp_result as rresult;
 pub use axum_resp_result_macro::resp_result;
 pub use axum_resp_result_macro::RespError;
-pub use convert::Fallible;
+#[doc(hidden)]
+pub use convert::__async_body;
+#[cfg(feature = "garde")]
+pub use convert::from_request::ByGarde;
+#[cfg(feature = "validator")]
+pub use convert::from_request::ByValidator;
+#[cfg(any(feature = "actix-web", feature = "salvo"))]
+pub use convert::from_request::RespRejection;
+#[cfg(feature = "salvo")]
+pub use convert::from_request::SalvoPayload;
+pub use convert::{Fallible, FallibleOption, FromFailure};
 pub use http::StatusCode;
@@ -0,0 +1,352 @@
+use http::StatusCode;
+use serde::{
+    ser::{SerializeSeq, SerializeStruct},
+    Serialize, Serializer,
+};
+
+use crate::{
+    config::InnerSerdeConfig, extra_flag::effect::Effects, get_config, RespError, RespResult,
+};
+
+use super::{EnvelopeConfig, LoadSerde, RespBody};
+
+/// the success body carrying a batch of [`RespResult`], for the bulk operations
+///
+/// - every item is serialized into the same envelope as a single response,
+///   except the request context fields, which only appear on the outer envelope
+/// - the status is `200 OK` if all items succeed, the common error status if all items
+///   fail with the same status, otherwise `207 Multi-Status`
+///
+/// # Example
+///
+/// ```rust
+/// use axum_resp_result::{BatchResp, NotFound, RespResult};
+/// use http::StatusCode;
+///
+/// let batch = [1, 2, 3]
+///     .into_iter()
+///     .map(|id| if id == 2 { Err(NotFound) } else { Ok(id) })
+///     .collect::<BatchResp<_, _>>();
+///
+/// assert_eq!(batch.summary().failed(), 1);
+///
+/// let resp = RespResult::<_, NotFound>::Success(batch).into_http_response();
+/// assert_eq!(resp.status(), StatusCode::MULTI_STATUS);
+/// ```
+pub struct BatchResp<T, E> {
+    body: BatchBody<T, E>,
+}
+
+/// the serialized body of [`BatchResp`], with the `items` and the `summary`
+pub struct BatchBody<T, E> {
+    items: Vec<RespResult<T, E>>,
+    summary: BatchSummary,
+}
+
+/// the counts of items in [`BatchResp`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct BatchSummary {
+    total: usize,
+    succeeded: usize,
+    failed: usize,
+}
+
+impl BatchSummary {
+    /// the number of all items
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// the number of succeeded items
+    pub fn succeeded(&self) -> usize {
+        self.succeeded
+    }
+
+    /// the number of failed items
+    pub fn failed(&self) -> usize {
+        self.failed
+    }
+}
+
+impl<T, E> BatchResp<T, E> {
+    /// create a new [`BatchResp`] with `items`
+    pub fn new(items: Vec<RespResult<T, E>>) -> Self {
+        let succeeded = items
+            .iter()
+            .filter(|item| matches!(item, RespResult::Success(_)))
+            .count();
+        let summary = BatchSummary {
+            total: items.len(),
+            succeeded,
+            failed: items.len() - succeeded,
+        };
+        Self {
+            body: BatchBody { items, summary },
+        }
+    }
+
+    /// the items of this batch
+    pub fn items(&self) -> &[RespResult<T, E>] {
+        &self.body.items
+    }
+
+    /// the summary of this batch
+    pub fn summary(&self) -> BatchSummary {
+        self.body.summary
+    }
+
+    /// get the inner items
+    pub fn into_items(self) -> Vec<RespResult<T, E>> {
+        self.body.items
+    }
+}
+
+impl<T, E: RespError> BatchResp<T, E> {
+    /// the overall status of this batch
+    /// - `200 OK` if no item fails
+    /// - the common status if all items fail with the same status
+    /// - `207 Multi-Status` otherwise
+    pub fn status(&self) -> StatusCode {
+        let mut errors = self.body.items.iter().filter_map(|item| match item {
+            RespResult::Success(_) => None,
+            RespResult::Err(err) => Some(err.http_code()),
+        });
+        let Some(first) = errors.next() else {
+            return StatusCode::OK;
+        };
+        if self.body.summary.succeeded == 0 && errors.all(|status| status == first) {
+            first
+        } else {
+            StatusCode::MULTI_STATUS
+        }
+    }
+}
+
+impl<T, E, R> FromIterator<R> for BatchResp<T, E>
+where
+    R: Into<RespResult<T, E>>,
+{
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        Self::new(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T, E> BatchBody<T, E>
+where
+    T: RespBody,
+    E: RespError,
+{
+    fn serialize_with_config<S>(
+        &self,
+        cfg: &InnerSerdeConfig,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        struct Items<'i, T, E>(&'i [RespResult<T, E>], &'i InnerSerdeConfig);
+
+        impl<T: RespBody, E: RespError> Serialize for Items<'_, T, E> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                struct Item<'i, T, E>(&'i RespResult<T, E>, &'i InnerSerdeConfig);
+
+                impl<T: RespBody, E: RespError> Serialize for Item<'_, T, E> {
+                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: Serializer,
+                    {
+                        self.0.serialize_item(self.1, serializer)
+                    }
+                }
+
+                let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+                for item in self.0 {
+                    seq.serialize_element(&Item(item, self.1))?;
+                }
+                seq.end()
+            }
+        }
+
+        let mut body = serializer.serialize_struct("BatchBody", 2)?;
+        body.serialize_field("items", &Items(&self.items, cfg))?;
+        body.serialize_field("summary", &self.summary)?;
+        body.end()
+    }
+}
+
+/// serialize with the global serde config, the [`BatchResp`] inside [`RespResult`]
+/// will be serialized with the config of the outer envelope instead
+impl<T, E> Serialize for BatchBody<T, E>
+where
+    T: RespBody,
+    E: RespError,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize_with_config(&get_config().serde, serializer)
+    }
+}
+
+impl<T, E> LoadSerde for BatchResp<T, E>
+where
+    T: RespBody + 'static,
+    E: RespError + 'static,
+{
+    type SerdeData = BatchBody<T, E>;
+
+    #[inline]
+    fn load_serde(&self) -> &Self::SerdeData {
+        &self.body
+    }
+}
+
+impl<T, E> Effects for BatchResp<T, E> {}
+
+impl<T, E> RespBody for BatchResp<T, E>
+where
+    T: RespBody + 'static,
+    E: RespError + 'static,
+{
+    #[inline]
+    fn success_status(&self) -> Option<StatusCode> {
+        Some(self.status())
+    }
+
+    #[inline]
+    fn serialize_body<S>(&self, cfg: EnvelopeConfig<'_>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.body.serialize_with_config(cfg.0, serializer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use http::StatusCode;
+    use serde_json::{json, Value};
+
+    use crate::{config::InnerSerdeConfig, NotFound, RespError, RespResult, SerdeConfig};
+
+    use super::BatchResp;
+
+    struct Conflict;
+
+    impl RespError for Conflict {
+        fn log_message(&self) -> Cow<'_, str> {
+            "Conflict".into()
+        }
+
+        fn http_code(&self) -> StatusCode {
+            StatusCode::CONFLICT
+        }
+
+        #[cfg(feature = "extra-error")]
+        type ExtraMessage = crate::Nil;
+
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Self::ExtraMessage {
+            crate::Nil
+        }
+    }
+
+    #[test]
+    fn test_status() {
+        let batch = BatchResp::<i32, NotFound>::from_iter([Ok(1), Ok(2)]);
+        assert_eq!(batch.status(), StatusCode::OK);
+
+        let batch = BatchResp::<i32, NotFound>::from_iter([Ok(1), Err(NotFound)]);
+        assert_eq!(batch.status(), StatusCode::MULTI_STATUS);
+
+        let batch = BatchResp::<i32, NotFound>::from_iter([Err(NotFound), Err(NotFound)]);
+        assert_eq!(batch.status(), StatusCode::NOT_FOUND);
+
+        let batch = BatchResp::<i32, crate::BoxRespError>::from_iter([
+            RespResult::err(NotFound.into()),
+            RespResult::err(crate::BoxRespError::new(Conflict)),
+        ]);
+        assert_eq!(batch.status(), StatusCode::MULTI_STATUS);
+
+        let batch = BatchResp::<i32, NotFound>::new(Vec::new());
+        assert_eq!(batch.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn test_serialize() {
+        let batch = BatchResp::<i32, NotFound>::from_iter([Ok(1), Err(NotFound)]);
+        assert_eq!(batch.summary().total(), 2);
+        assert_eq!(batch.summary().succeeded(), 1);
+
+        let resp = RespResult::<_, NotFound>::Success(batch).into_http_response();
+        assert_eq!(resp.status(), StatusCode::MULTI_STATUS);
+
+        let body = serde_json::from_slice::<Value>(resp.body()).unwrap();
+        let body = &body["body"];
+        assert_eq!(
+            body["summary"],
+            json!({ "total": 2, "succeeded": 1, "failed": 1 })
+        );
+        assert_eq!(body["items"][0]["body"], json!(1));
+        assert_eq!(body["items"][0]["is-ok"], json!(true));
+        assert_eq!(body["items"][1]["error-message"], json!("Not Found"));
+    }
+
+    struct ContextConfig;
+
+    impl SerdeConfig for ContextConfig {
+        fn body_name(&self) -> Cow<'static, str> {
+            "data".into()
+        }
+
+        fn signed_status(&self) -> Option<crate::StatusSign> {
+            None
+        }
+
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Option<Cow<'static, str>> {
+            None
+        }
+
+        fn request_id_name(&self) -> Option<Cow<'static, str>> {
+            Some("request-id".into())
+        }
+
+        fn timestamp_name(&self) -> Option<Cow<'static, str>> {
+            Some("timestamp".into())
+        }
+
+        fn elapsed_name(&self) -> Option<Cow<'static, str>> {
+            Some("elapsed".into())
+        }
+    }
+
+    #[test]
+    fn test_serialize_with_outer_config() {
+        let cfg = InnerSerdeConfig::into_inner(&ContextConfig);
+        let batch = BatchResp::<i32, NotFound>::from_iter([Ok(1), Err(NotFound)]);
+
+        let mut buf = Vec::new();
+        RespResult::<_, NotFound>::Success(batch)
+            .serialize_with_config(&cfg, &mut serde_json::Serializer::new(&mut buf))
+            .unwrap();
+        let body = serde_json::from_slice::<Value>(&buf).unwrap();
+
+        assert!(body["timestamp"].is_string());
+        assert!(body.get("request-id").is_some());
+        assert!(body.get("elapsed").is_some());
+        assert_eq!(
+            body["data"]["items"],
+            json!([
+                { "error-message": null, "data": 1 },
+                { "error-message": "Not Found", "data": null },
+            ])
+        );
+    }
+}
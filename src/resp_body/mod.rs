@@ -1,8 +1,81 @@
-use crate::extra_flag::effect::Effects;
+use http::StatusCode;
+use serde::{Serialize, Serializer};
 
+use crate::{config::InnerSerdeConfig, extra_flag::effect::Effects};
+
+pub use self::batch::{BatchBody, BatchResp, BatchSummary};
+pub(crate) use self::paged::WithPageMeta;
+pub use self::paged::{PageMeta, Paged};
 pub use self::serde_data::LoadSerde;
+pub use self::status::{Accepted, Created, NoContent, PartialContent};
+pub use self::warning::{Warning, WithWarnings};
 
+mod batch;
+mod paged;
 mod serde_data;
-pub trait RespBody: LoadSerde + Effects {}
+mod status;
+mod warning;
+
+/// the body of [`RespResult::Success`](crate::RespResult::Success)
+///
+/// all the `T: Serialize + 'static` is [`RespBody`] with default behavior,
+/// the type not implement `Serialize` can implement it with [`LoadSerde`] and [`Effects`]
+/// for declaring its own status code and headers
+pub trait RespBody: LoadSerde + Effects {
+    /// the status code of the success response
+    /// - `Some(_)` overwrite the default `200 OK`
+    /// - `None` using the default
+    ///
+    /// the [`Effects::status_effect`] has higher priority
+    #[inline]
+    fn success_status(&self) -> Option<StatusCode> {
+        None
+    }
+
+    /// the metadata of the page, if the body is a page of items
+    /// - `Some(_)` the metadata will be serialized according to [`SerdeConfig::page_meta_name`](crate::SerdeConfig::page_meta_name)
+    /// - `None` the body is serialized as it is
+    #[inline]
+    fn page_meta(&self) -> Option<&PageMeta> {
+        None
+    }
+
+    /// the warnings of the success response,
+    /// they will be serialized into the field set by [`SerdeConfig::warnings_name`](crate::SerdeConfig::warnings_name)
+    #[inline]
+    fn warnings(&self) -> &[Warning] {
+        &[]
+    }
+
+    /// serialize the body with the serde config of the envelope,
+    /// the body nesting [`RespResult`](crate::RespResult) like [`BatchResp`] overwrite it,
+    /// and the wrapper bodies forward it to the inner body
+    #[doc(hidden)]
+    #[inline]
+    fn serialize_body<S>(&self, _cfg: EnvelopeConfig<'_>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.load_serde().serialize(serializer)
+    }
+}
+
+/// the serde config of the envelope passing to [`RespBody::serialize_body`],
+/// it is opaque and can not be named outside the crate
+#[derive(Clone, Copy)]
+pub struct EnvelopeConfig<'a>(pub(crate) &'a InnerSerdeConfig);
+
+/// the body serialized with the serde config of the envelope
+pub(crate) struct WithConfig<'a, T>(pub(crate) &'a T, pub(crate) &'a InnerSerdeConfig);
+
+impl<T: RespBody> Serialize for WithConfig<'_, T> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize_body(EnvelopeConfig(self.1), serializer)
+    }
+}
 
 impl<T> RespBody for T where T: serde::Serialize + 'static {}
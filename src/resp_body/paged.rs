@@ -0,0 +1,275 @@
+use std::fmt::Write;
+
+use http::{header::LINK, Uri};
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+use crate::{extra_flag::effect::Effects, ExtraFlag, ExtraFlags};
+
+use super::{LoadSerde, RespBody};
+
+/// the metadata of a page, provided by [`RespBody::page_meta`]
+///
+/// it will be serialized alongside the `items` in the body,
+/// or as the sibling field of body named by [`SerdeConfig::page_meta_name`](crate::SerdeConfig::page_meta_name)
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct PageMeta {
+    total: Option<u64>,
+    page: Option<u64>,
+    page_size: Option<u64>,
+    next_cursor: Option<String>,
+}
+
+impl PageMeta {
+    /// the number of all items
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// the current page number, start from `1`
+    pub fn page(&self) -> Option<u64> {
+        self.page
+    }
+
+    /// the max number of items in a page
+    pub fn page_size(&self) -> Option<u64> {
+        self.page_size
+    }
+
+    /// the cursor of the next page
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor.as_deref()
+    }
+}
+
+/// the success body of a page of items
+///
+/// it is serialized as `{items, total, page, page_size, next_cursor}` by default,
+/// the metadata can be moved into a sibling field of body by [`SerdeConfig::page_meta_name`](crate::SerdeConfig::page_meta_name)
+///
+/// # Example
+///
+/// ```rust
+/// use axum_resp_result::{PageQuery, Paged, RespResult, ValidationErrors};
+///
+/// let query = PageQuery::default();
+/// let page = Paged::new(vec![1, 2, 3]).with_query(&query).with_total(3);
+///
+/// let resp = RespResult::<_, ValidationErrors>::Success(page).into_http_response();
+/// let body = serde_json::from_slice::<serde_json::Value>(resp.body()).unwrap();
+/// assert_eq!(body["body"]["items"], serde_json::json!([1, 2, 3]));
+/// assert_eq!(body["body"]["total"], 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Paged<T> {
+    items: Vec<T>,
+    meta: PageMeta,
+}
+
+impl<T> Paged<T> {
+    /// create a new [`Paged`] with `items` and empty metadata
+    pub fn new(items: Vec<T>) -> Self {
+        Self {
+            items,
+            meta: PageMeta::default(),
+        }
+    }
+
+    /// set the number of all items
+    pub fn with_total(mut self, total: u64) -> Self {
+        self.meta.total = Some(total);
+        self
+    }
+
+    /// set the current page number and the page size
+    pub fn with_page(mut self, page: u64, page_size: u64) -> Self {
+        self.meta.page = Some(page);
+        self.meta.page_size = Some(page_size);
+        self
+    }
+
+    /// set the current page number and the page size by `query`
+    pub fn with_query(self, query: &crate::PageQuery) -> Self {
+        self.with_page(query.page, query.per_page)
+    }
+
+    /// set the cursor of the next page
+    pub fn with_next_cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.meta.next_cursor = Some(cursor.into());
+        self
+    }
+
+    /// the items of this page
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// the metadata of this page
+    pub fn meta(&self) -> &PageMeta {
+        &self.meta
+    }
+
+    /// get the inner items
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    /// the [RFC 8288](https://www.rfc-editor.org/rfc/rfc8288) `Link` header of the pages
+    /// near this page, based on the request `uri`
+    ///
+    /// - with the next cursor, the `next` link is given by replacing the query `cursor`
+    /// - otherwise with the page number and page size, the `first`, `prev`, `next` and `last` links
+    ///   are given by replacing the query `page`. without the total, the `next` link is given
+    ///   if this page is full, and no `last` link
+    ///
+    /// the flags is empty if no link is available
+    pub fn link_flags(&self, uri: &Uri) -> ExtraFlags {
+        let mut links = Vec::new();
+        if let Some(cursor) = &self.meta.next_cursor {
+            links.push(("next", with_query(uri, "cursor", cursor)));
+        } else if let (Some(page), Some(size)) = (self.meta.page, self.meta.page_size) {
+            let page_link = |page: u64| with_query(uri, "page", &page.to_string());
+            let last = self
+                .meta
+                .total
+                .map(|total| total.div_ceil(size.max(1)).max(1));
+            let has_next = match last {
+                Some(last) => page < last,
+                None => self.items.len() as u64 >= size,
+            };
+
+            links.push(("first", page_link(1)));
+            if page > 1 {
+                links.push(("prev", page_link(page - 1)));
+            }
+            if has_next {
+                links.push(("next", page_link(page + 1)));
+            }
+            if let Some(last) = last {
+                links.push(("last", page_link(last)));
+            }
+        }
+
+        if links.is_empty() {
+            return ().into();
+        }
+        let value = links
+            .iter()
+            .map(|(rel, link)| format!("<{link}>; rel=\"{rel}\""))
+            .collect::<Vec<_>>()
+            .join(", ");
+        ExtraFlag::insert_header(LINK, value).into()
+    }
+}
+
+/// the `uri` with the query `key` replaced by `value`
+fn with_query(uri: &Uri, key: &str, value: &str) -> String {
+    let mut link = String::new();
+    if let (Some(scheme), Some(authority)) = (uri.scheme_str(), uri.authority()) {
+        let _ = write!(link, "{scheme}://{authority}");
+    }
+    link.push_str(uri.path());
+    link.push('?');
+    for pair in uri.query().unwrap_or_default().split('&') {
+        let name = pair.split('=').next().unwrap_or_default();
+        if !pair.is_empty() && name != key {
+            link.push_str(pair);
+            link.push('&');
+        }
+    }
+    let _ = write!(link, "{key}=");
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                link.push(byte as char)
+            }
+            _ => {
+                let _ = write!(link, "%{byte:02X}");
+            }
+        }
+    }
+    link
+}
+
+impl<T: Serialize + 'static> LoadSerde for Paged<T> {
+    type SerdeData = Vec<T>;
+
+    #[inline]
+    fn load_serde(&self) -> &Self::SerdeData {
+        &self.items
+    }
+}
+
+impl<T> Effects for Paged<T> {}
+
+impl<T: Serialize + 'static> RespBody for Paged<T> {
+    #[inline]
+    fn page_meta(&self) -> Option<&PageMeta> {
+        Some(&self.meta)
+    }
+}
+
+/// the body with the page metadata alongside the `items`
+pub(crate) struct WithPageMeta<'a, T>(pub(crate) &'a T, pub(crate) &'a PageMeta);
+
+impl<T: Serialize> Serialize for WithPageMeta<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let WithPageMeta(items, meta) = self;
+        let mut body = serializer.serialize_struct("Paged", 5)?;
+        body.serialize_field("items", items)?;
+        body.serialize_field("total", &meta.total)?;
+        body.serialize_field("page", &meta.page)?;
+        body.serialize_field("page_size", &meta.page_size)?;
+        body.serialize_field("next_cursor", &meta.next_cursor)?;
+        body.end()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use http::{header::LINK, Uri};
+
+    use crate::{NotFound, RespResult};
+
+    use super::Paged;
+
+    fn link(page: Paged<i32>, uri: &str) -> Option<String> {
+        let flags = page.link_flags(&uri.parse::<Uri>().unwrap());
+        let resp = RespResult::<_, NotFound>::Success(page)
+            .with_flags(flags)
+            .into_http_response();
+        resp.headers()
+            .get(LINK)
+            .map(|value| value.to_str().unwrap().to_owned())
+    }
+
+    #[test]
+    fn test_page_links() {
+        let page = Paged::new(vec![3, 4]).with_page(2, 2).with_total(5);
+        assert_eq!(
+            link(page, "/users?page=2&per_page=2&sort=name").as_deref(),
+            Some(
+                "</users?per_page=2&sort=name&page=1>; rel=\"first\", \
+                 </users?per_page=2&sort=name&page=1>; rel=\"prev\", \
+                 </users?per_page=2&sort=name&page=3>; rel=\"next\", \
+                 </users?per_page=2&sort=name&page=3>; rel=\"last\""
+            )
+        );
+
+        let page = Paged::new(vec![1]).with_page(1, 2);
+        assert_eq!(
+            link(page, "/users").as_deref(),
+            Some("</users?page=1>; rel=\"first\"")
+        );
+
+        let page = Paged::new(vec![1]).with_next_cursor("a b");
+        assert_eq!(
+            link(page, "http://example.com/users?cursor=x").as_deref(),
+            Some("<http://example.com/users?cursor=a%20b>; rel=\"next\"")
+        );
+
+        assert_eq!(link(Paged::new(vec![1]), "/users"), None);
+    }
+}
@@ -1,6 +1,17 @@
+use std::any::TypeId;
+
+use crate::Nil;
+
 pub trait LoadSerde {
     type SerdeData: serde::Serialize;
     fn load_serde(&self) -> &Self::SerdeData;
+
+    /// whether the data is unit-like, [`Nil`] or `()`,
+    /// the unit-like success can respond `204 No Content` by [`RespConfig::unit_no_content`](crate::RespConfig::unit_no_content)
+    #[inline]
+    fn is_unit() -> bool {
+        false
+    }
 }
 
 impl<T> LoadSerde for T
@@ -12,4 +23,10 @@ where
     fn load_serde(&self) -> &Self::SerdeData {
         self
     }
+
+    #[inline]
+    fn is_unit() -> bool {
+        let ty = TypeId::of::<T>();
+        ty == TypeId::of::<Nil>() || ty == TypeId::of::<()>()
+    }
 }
@@ -0,0 +1,287 @@
+use std::fmt::Debug;
+
+use http::{
+    header::{CONTENT_RANGE, CONTENT_TYPE, LOCATION},
+    HeaderMap, HeaderName, HeaderValue, StatusCode,
+};
+
+use serde::Serializer;
+
+use crate::{
+    expect_ext::ExpectExt,
+    extra_flag::effect::{BodyEffect, Effects},
+    Nil, PageMeta, Warning,
+};
+
+use super::{EnvelopeConfig, LoadSerde, RespBody};
+
+macro_rules! status_body {
+    (
+        $(#[$meta:meta])*
+        $name:ident => $status:ident, $header:ident, $setter:ident
+    ) => {
+        $(#[$meta])*
+        pub struct $name<T> {
+            data: T,
+            header: Option<HeaderValue>,
+        }
+
+        impl<T> $name<T> {
+            #[doc = concat!("create a new [`", stringify!($name), "`] with `data`")]
+            #[inline]
+            pub fn new(data: T) -> Self {
+                Self { data, header: None }
+            }
+
+            #[doc = concat!("set the `", stringify!($header), "` header of the response")]
+            ///
+            /// ## Panic
+            /// the `value` is not a valid header value
+            #[inline]
+            pub fn $setter<V>(mut self, value: V) -> Self
+            where
+                V: TryInto<HeaderValue>,
+                V::Error: Debug,
+            {
+                self.header = Some(value.try_into().with_expect("Bad Header Value"));
+                self
+            }
+
+            /// get the inner data
+            #[inline]
+            pub fn into_inner(self) -> T {
+                self.data
+            }
+        }
+
+        impl<T: LoadSerde> LoadSerde for $name<T> {
+            type SerdeData = T::SerdeData;
+
+            #[inline]
+            fn load_serde(&self) -> &Self::SerdeData {
+                self.data.load_serde()
+            }
+
+            #[inline]
+            fn is_unit() -> bool {
+                T::is_unit()
+            }
+        }
+
+        impl<T: Effects> Effects for $name<T> {
+            #[inline]
+            fn body_effect(&self, body: &mut Vec<u8>) -> BodyEffect {
+                self.data.body_effect(body)
+            }
+
+            #[inline]
+            fn status_effect(&self) -> Option<StatusCode> {
+                self.data.status_effect()
+            }
+
+            #[inline]
+            fn headers_effect(&self, map: &mut HeaderMap) {
+                self.data.headers_effect(map);
+                if let Some(value) = &self.header {
+                    map.insert($header, value.clone());
+                }
+            }
+        }
+
+        impl<T: RespBody> RespBody for $name<T> {
+            /// the status of the wrapper always overrides the one declared by the inner body
+            #[inline]
+            fn success_status(&self) -> Option<StatusCode> {
+                Some(StatusCode::$status)
+            }
+
+            #[inline]
+            fn page_meta(&self) -> Option<&PageMeta> {
+                self.data.page_meta()
+            }
+
+            #[inline]
+            fn warnings(&self) -> &[Warning] {
+                self.data.warnings()
+            }
+
+            #[inline]
+            fn serialize_body<S>(
+                &self,
+                cfg: EnvelopeConfig<'_>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                self.data.serialize_body(cfg, serializer)
+            }
+        }
+    };
+}
+
+status_body! {
+    /// the success body responding `201 Created`, with optional `Location` header
+    Created => CREATED, LOCATION, with_location
+}
+
+status_body! {
+    /// the success body responding `202 Accepted`, with optional `Location` header
+    /// pointing to the status of the accepted job
+    Accepted => ACCEPTED, LOCATION, with_location
+}
+
+status_body! {
+    /// the success body responding `206 Partial Content`, with optional `Content-Range` header
+    PartialContent => PARTIAL_CONTENT, CONTENT_RANGE, with_content_range
+}
+
+/// the success body responding `204 No Content` with empty body and without `Content-Type`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoContent;
+
+impl LoadSerde for NoContent {
+    type SerdeData = Nil;
+
+    #[inline]
+    fn load_serde(&self) -> &Self::SerdeData {
+        &Nil
+    }
+
+    #[inline]
+    fn is_unit() -> bool {
+        true
+    }
+}
+
+impl Effects for NoContent {
+    #[inline]
+    fn body_effect(&self, body: &mut Vec<u8>) -> BodyEffect {
+        body.clear();
+        BodyEffect::Empty
+    }
+
+    #[inline]
+    fn headers_effect(&self, map: &mut HeaderMap) {
+        map.remove::<&HeaderName>(&CONTENT_TYPE);
+    }
+}
+
+impl RespBody for NoContent {
+    #[inline]
+    fn success_status(&self) -> Option<StatusCode> {
+        Some(StatusCode::NO_CONTENT)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use http::{
+        header::{CONTENT_TYPE, ETAG, LOCATION},
+        StatusCode,
+    };
+
+    use crate::{ExtraFlag, FlagWrap, Paged, RespError, RespResult, WithWarnings};
+
+    use super::{Accepted, Created, NoContent, PartialContent};
+
+    struct MockErr;
+
+    impl RespError for MockErr {
+        fn log_message(&self) -> std::borrow::Cow<'_, str> {
+            "Mock Error".into()
+        }
+        #[cfg(feature = "extra-error")]
+        type ExtraMessage = String;
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Self::ExtraMessage {
+            "Mock".into()
+        }
+    }
+
+    #[test]
+    fn test_created() {
+        let resp = RespResult::<_, MockErr>::Success(Created::new(1).with_location("/users/1"))
+            .into_http_response();
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        assert_eq!(resp.headers()[LOCATION], "/users/1");
+    }
+
+    #[test]
+    fn test_status_body() {
+        let resp = RespResult::<_, MockErr>::Success(Accepted::new(1)).into_http_response();
+        assert_eq!(resp.status(), StatusCode::ACCEPTED);
+        assert!(resp.headers().get(LOCATION).is_none());
+
+        let resp =
+            RespResult::<_, MockErr>::Success(PartialContent::new([1, 2])).into_http_response();
+        assert_eq!(resp.status(), StatusCode::PARTIAL_CONTENT);
+
+        let resp = RespResult::<_, MockErr>::Success(NoContent).into_http_response();
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        assert!(resp.headers().is_empty());
+
+        let resp = RespResult::<Created<i32>, _>::Err(MockErr).into_http_response();
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn test_inner_effects() {
+        let body = Created::new(FlagWrap::new(1, ExtraFlag::insert_header(ETAG, "\"1\"")))
+            .with_location("/users/1");
+        let resp = RespResult::<_, MockErr>::Success(body).into_http_response();
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        assert_eq!(resp.headers()[ETAG], "\"1\"");
+        assert_eq!(resp.headers()[LOCATION], "/users/1");
+
+        let body = Created::new(NoContent).with_location("/users/1");
+        let resp = RespResult::<_, MockErr>::Success(body).into_http_response();
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        assert!(resp.headers().get(CONTENT_TYPE).is_none());
+        assert_eq!(resp.headers()[LOCATION], "/users/1");
+        assert!(resp.body().is_empty());
+    }
+
+    #[test]
+    fn test_inner_page_meta() {
+        let body = PartialContent::new(Paged::new(vec![1, 2]).with_page(1, 2).with_total(5))
+            .with_content_range("items 0-1/5");
+        let resp = RespResult::<_, MockErr>::Success(body).into_http_response();
+        assert_eq!(resp.status(), StatusCode::PARTIAL_CONTENT);
+
+        let body = serde_json::from_slice::<serde_json::Value>(resp.body()).unwrap();
+        assert_eq!(body["body"]["items"], serde_json::json!([1, 2]));
+        assert_eq!(body["body"]["total"], 5);
+        assert_eq!(body["body"]["page_size"], 2);
+    }
+
+    #[test]
+    fn test_inner_warnings() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_688_169_599);
+        let body = Accepted::new(
+            WithWarnings::new(1)
+                .warn("deprecated", "use `/v2/jobs` instead")
+                .deprecated(time)
+                .sunset(time),
+        )
+        .with_location("/jobs/1");
+        let resp = RespResult::<_, MockErr>::Success(body).into_http_response();
+        assert_eq!(resp.status(), StatusCode::ACCEPTED);
+        assert_eq!(resp.headers()[LOCATION], "/jobs/1");
+        assert_eq!(resp.headers()["deprecation"], "@1688169599");
+        assert_eq!(resp.headers()["sunset"], "Fri, 30 Jun 2023 23:59:59 GMT");
+
+        let body = serde_json::from_slice::<serde_json::Value>(resp.body()).unwrap();
+        assert_eq!(body["warnings"][0]["code"], "deprecated");
+    }
+
+    #[test]
+    fn test_flag_overwrite() {
+        let resp = RespResult::<_, MockErr>::Success(Created::new(1))
+            .with_flags(ExtraFlag::status(StatusCode::OK))
+            .into_http_response();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+}
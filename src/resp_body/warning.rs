@@ -0,0 +1,233 @@
+use std::{
+    borrow::Cow,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use http::{HeaderMap, HeaderName, HeaderValue, StatusCode};
+use serde::{Serialize, Serializer};
+
+use crate::{
+    config::UtcTime,
+    extra_flag::effect::{BodyEffect, Effects},
+    RespResult,
+};
+
+use super::{EnvelopeConfig, LoadSerde, PageMeta, RespBody};
+
+const DEPRECATION: HeaderName = HeaderName::from_static("deprecation");
+const SUNSET: HeaderName = HeaderName::from_static("sunset");
+
+/// the structured warning of a successful response, like a deprecated parameter or truncated results
+///
+/// the warnings will be serialized into the field set by [`SerdeConfig::warnings_name`](crate::SerdeConfig::warnings_name)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Warning {
+    code: Cow<'static, str>,
+    message: Cow<'static, str>,
+}
+
+impl Warning {
+    /// create a new [`Warning`] with provide `code` and `message`
+    pub fn new(code: impl Into<Cow<'static, str>>, message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+
+    /// the code of this [`Warning`]
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// the message of this [`Warning`]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// the success body with warnings, and the deprecation notice of the endpoint
+///
+/// - the warnings are serialized into the field set by [`SerdeConfig::warnings_name`](crate::SerdeConfig::warnings_name)
+/// - the `Deprecation` header ([RFC 9745](https://www.rfc-editor.org/rfc/rfc9745)) and
+///   `Sunset` header ([RFC 8594](https://www.rfc-editor.org/rfc/rfc8594)) are set if provided
+///
+/// # Example
+///
+/// ```rust
+/// use axum_resp_result::{RespResult, ValidationErrors, Warning};
+///
+/// let resp = RespResult::<_, ValidationErrors>::Success(vec![1, 2])
+///     .with_warnings([Warning::new("truncated", "only the first 2 items are returned")])
+///     .into_http_response();
+/// let body = serde_json::from_slice::<serde_json::Value>(resp.body()).unwrap();
+/// assert_eq!(body["warnings"][0]["code"], "truncated");
+/// ```
+pub struct WithWarnings<T> {
+    data: T,
+    warnings: Vec<Warning>,
+    deprecation: Option<SystemTime>,
+    sunset: Option<SystemTime>,
+}
+
+impl<T> WithWarnings<T> {
+    /// create a new [`WithWarnings`] with `data` and without any warning
+    #[inline]
+    pub fn new(data: T) -> Self {
+        Self {
+            data,
+            warnings: Vec::new(),
+            deprecation: None,
+            sunset: None,
+        }
+    }
+
+    /// adding a warning with provide `code` and `message`
+    #[inline]
+    pub fn warn(
+        self,
+        code: impl Into<Cow<'static, str>>,
+        message: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.with_warning(Warning::new(code, message))
+    }
+
+    /// adding a [`Warning`]
+    #[inline]
+    pub fn with_warning(mut self, warning: Warning) -> Self {
+        self.warnings.push(warning);
+        self
+    }
+
+    /// mark the endpoint deprecated since `since`, setting the `Deprecation` header
+    #[inline]
+    pub fn deprecated(mut self, since: SystemTime) -> Self {
+        self.deprecation = Some(since);
+        self
+    }
+
+    /// the endpoint will be unavailable at `at`, setting the `Sunset` header
+    #[inline]
+    pub fn sunset(mut self, at: SystemTime) -> Self {
+        self.sunset = Some(at);
+        self
+    }
+
+    /// all the warnings
+    #[inline]
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    /// get the inner data
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+impl<T, E> RespResult<T, E> {
+    #[inline]
+    /// covert a [`RespResult::<T, E>`] into [`RespResult<WithWarnings<T>, E>`] with provide warnings
+    pub fn with_warnings(
+        self,
+        warnings: impl IntoIterator<Item = Warning>,
+    ) -> RespResult<WithWarnings<T>, E> {
+        self.map(|data| {
+            warnings
+                .into_iter()
+                .fold(WithWarnings::new(data), WithWarnings::with_warning)
+        })
+    }
+}
+
+impl<T: LoadSerde> LoadSerde for WithWarnings<T> {
+    type SerdeData = T::SerdeData;
+
+    #[inline]
+    fn load_serde(&self) -> &Self::SerdeData {
+        self.data.load_serde()
+    }
+
+    #[inline]
+    fn is_unit() -> bool {
+        T::is_unit()
+    }
+}
+
+impl<T: Effects> Effects for WithWarnings<T> {
+    #[inline]
+    fn body_effect(&self, body: &mut Vec<u8>) -> BodyEffect {
+        self.data.body_effect(body)
+    }
+
+    #[inline]
+    fn status_effect(&self) -> Option<StatusCode> {
+        self.data.status_effect()
+    }
+
+    fn headers_effect(&self, map: &mut HeaderMap) {
+        self.data.headers_effect(map);
+        if let Some(since) = self.deprecation {
+            let secs = since
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default();
+            if let Ok(value) = HeaderValue::try_from(format!("@{secs}")) {
+                map.insert(DEPRECATION, value);
+            }
+        }
+        if let Some(at) = self.sunset {
+            if let Ok(value) = HeaderValue::try_from(UtcTime::new(at).http_date()) {
+                map.insert(SUNSET, value);
+            }
+        }
+    }
+}
+
+impl<T: RespBody> RespBody for WithWarnings<T> {
+    #[inline]
+    fn success_status(&self) -> Option<StatusCode> {
+        self.data.success_status()
+    }
+
+    #[inline]
+    fn page_meta(&self) -> Option<&PageMeta> {
+        self.data.page_meta()
+    }
+
+    #[inline]
+    fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    #[inline]
+    fn serialize_body<S>(&self, cfg: EnvelopeConfig<'_>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.data.serialize_body(cfg, serializer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::{NotFound, RespResult};
+
+    use super::WithWarnings;
+
+    #[test]
+    fn test_deprecation_headers() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_688_169_599);
+        let body = WithWarnings::new(1)
+            .warn("deprecated", "use `/v2/users` instead")
+            .deprecated(time)
+            .sunset(time);
+        let resp = RespResult::<_, NotFound>::Success(body).into_http_response();
+
+        assert_eq!(resp.headers()["deprecation"], "@1688169599");
+        assert_eq!(resp.headers()["sunset"], "Fri, 30 Jun 2023 23:59:59 GMT");
+    }
+}
@@ -0,0 +1,280 @@
+use std::{any::TypeId, borrow::Cow, collections::HashMap, error::Error as StdError, sync::RwLock};
+
+use http::StatusCode;
+use once_cell::sync::Lazy;
+
+use crate::{MessageKey, NotFound, ValidationErrors};
+
+use super::{ErrorDetail, RespError};
+
+type Downcaster = Box<dyn Fn(&(dyn StdError + 'static)) -> Option<Classified> + Send + Sync>;
+
+/// the registered downcasters keyed by the error type, at most one for each type,
+/// the prefab errors of this crate are registered by default
+static REGISTRY: Lazy<RwLock<HashMap<TypeId, Downcaster>>> = Lazy::new(|| {
+    RwLock::new(HashMap::from([
+        registered::<ValidationErrors>(resp_error_downcaster::<ValidationErrors>()),
+        registered::<NotFound>(resp_error_downcaster::<NotFound>()),
+        #[cfg(feature = "axum")]
+        registered::<crate::AxumRejection>(resp_error_downcaster::<crate::AxumRejection>()),
+    ]))
+});
+
+fn registered<E: 'static>(downcaster: Downcaster) -> (TypeId, Downcaster) {
+    (TypeId::of::<E>(), downcaster)
+}
+
+/// the response of an error found in the error chain
+struct Classified {
+    status: StatusCode,
+    message: Cow<'static, str>,
+    message_key: Option<MessageKey<'static>>,
+    details: Option<Vec<ErrorDetail>>,
+}
+
+fn resp_error_downcaster<E>() -> Downcaster
+where
+    E: RespError + StdError + 'static,
+{
+    Box::new(|err| {
+        let err = err.downcast_ref::<E>()?;
+        Some(Classified {
+            status: err.http_code(),
+            message: err.resp_message().into_owned().into(),
+            message_key: err.resp_message_key().map(MessageKey::into_owned),
+            details: err.resp_details().map(Cow::into_owned),
+        })
+    })
+}
+
+/// the [`RespError`] wrapping [`anyhow::Error`]
+///
+/// the response is decided by the first error in the error chain which can be downcast to a registered type,
+/// registered by [`AnyRespError::register`] or [`AnyRespError::register_with`].
+/// registering the same type again replaces the previous one.
+/// if no error matched, it will be an opaque `500 Internal Server Error`
+/// whose response message never contains the internal details
+///
+/// the [`RespError::log_message`] always contains the full error chain and the backtrace if captured
+///
+/// # Example
+///
+/// ```rust
+/// use axum_resp_result::{AnyRespError, RespError};
+/// use http::StatusCode;
+///
+/// AnyRespError::register_with::<std::num::ParseIntError, _>(|_| {
+///     (StatusCode::BAD_REQUEST, "Invalid Integer".into())
+/// });
+///
+/// let err = AnyRespError::from(anyhow::Error::from("abc".parse::<i32>().unwrap_err()));
+/// assert_eq!(err.http_code(), StatusCode::BAD_REQUEST);
+/// assert_eq!(err.resp_message(), "Invalid Integer");
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "anyhow")))]
+pub struct AnyRespError {
+    inner: anyhow::Error,
+    classified: Option<Classified>,
+}
+
+impl AnyRespError {
+    /// register the error type `E` implemented [`RespError`],
+    /// the response will follow the `E` when it is found in the error chain
+    pub fn register<E>()
+    where
+        E: RespError + StdError + Send + Sync + 'static,
+    {
+        Self::insert::<E>(resp_error_downcaster::<E>())
+    }
+
+    /// register the error type `E`, the response status code and message
+    /// will be provided by `classify` when `E` is found in the error chain
+    pub fn register_with<E, F>(classify: F)
+    where
+        E: StdError + 'static,
+        F: Fn(&E) -> (StatusCode, Cow<'static, str>) + Send + Sync + 'static,
+    {
+        Self::insert::<E>(Box::new(move |err| {
+            let (status, message) = classify(err.downcast_ref::<E>()?);
+            Some(Classified {
+                status,
+                message,
+                message_key: None,
+                details: None,
+            })
+        }))
+    }
+
+    fn insert<E: 'static>(downcaster: Downcaster) {
+        REGISTRY
+            .write()
+            .unwrap_or_else(|poison| poison.into_inner())
+            .insert(TypeId::of::<E>(), downcaster);
+    }
+
+    /// the wrapped [`anyhow::Error`]
+    pub fn inner(&self) -> &anyhow::Error {
+        &self.inner
+    }
+
+    /// get the wrapped [`anyhow::Error`]
+    pub fn into_inner(self) -> anyhow::Error {
+        self.inner
+    }
+}
+
+impl From<anyhow::Error> for AnyRespError {
+    fn from(inner: anyhow::Error) -> Self {
+        let classified = {
+            let registry = REGISTRY.read().unwrap_or_else(|poison| poison.into_inner());
+            inner
+                .chain()
+                .find_map(|err| registry.values().find_map(|downcast| downcast(err)))
+        };
+        Self { inner, classified }
+    }
+}
+
+impl std::fmt::Debug for AnyRespError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl std::fmt::Display for AnyRespError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl RespError for AnyRespError {
+    fn log_message(&self) -> Cow<'_, str> {
+        format!("{:?}", self.inner).into()
+    }
+
+    fn resp_message(&self) -> Cow<'_, str> {
+        match &self.classified {
+            Some(classified) => Cow::Borrowed(&classified.message),
+            None => "Internal Server Error".into(),
+        }
+    }
+
+    fn resp_message_key(&self) -> Option<MessageKey<'_>> {
+        self.classified.as_ref()?.message_key.clone()
+    }
+
+    fn resp_details(&self) -> Option<Cow<'_, [ErrorDetail]>> {
+        let details = self.classified.as_ref()?.details.as_deref()?;
+        Some(Cow::Borrowed(details))
+    }
+
+    fn http_code(&self) -> StatusCode {
+        self.classified
+            .as_ref()
+            .map(|classified| classified.status)
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    #[cfg(feature = "extra-error")]
+    type ExtraMessage = crate::Nil;
+
+    #[cfg(feature = "extra-error")]
+    fn extra_message(&self) -> Self::ExtraMessage {
+        crate::Nil
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::any::TypeId;
+
+    use anyhow::Context;
+    use http::StatusCode;
+
+    use crate::{NotFound, RespError, ValidationErrors};
+
+    use super::{AnyRespError, REGISTRY};
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("database password is 123456")]
+    struct DbError;
+
+    #[test]
+    fn test_opaque() {
+        let err = AnyRespError::from(anyhow::Error::from(DbError).context("load user"));
+
+        assert_eq!(err.http_code(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(err.resp_message(), "Internal Server Error");
+        assert!(err.log_message().contains("load user"));
+        assert!(err.log_message().contains("database password"));
+    }
+
+    #[test]
+    fn test_downcast_chain() {
+        let err = Err::<(), _>(NotFound)
+            .context("find user")
+            .context("handle request")
+            .unwrap_err();
+        let err = AnyRespError::from(err);
+
+        assert_eq!(err.http_code(), StatusCode::NOT_FOUND);
+        assert_eq!(err.resp_message(), "Not Found");
+
+        let err = AnyRespError::from(anyhow::Error::from(ValidationErrors::new().with(
+            "name",
+            "required",
+            "name is required",
+        )));
+        assert_eq!(err.http_code(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(err.resp_details().map(|d| d.len()), Some(1));
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("forbidden")]
+    struct Forbidden;
+
+    impl RespError for Forbidden {
+        fn log_message(&self) -> std::borrow::Cow<'_, str> {
+            "forbidden".into()
+        }
+
+        fn http_code(&self) -> StatusCode {
+            StatusCode::FORBIDDEN
+        }
+
+        #[cfg(feature = "extra-error")]
+        type ExtraMessage = crate::Nil;
+
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Self::ExtraMessage {
+            crate::Nil
+        }
+    }
+
+    #[test]
+    fn test_register() {
+        AnyRespError::register::<Forbidden>();
+
+        let err = AnyRespError::from(anyhow::Error::from(Forbidden).context("check permission"));
+        assert_eq!(err.http_code(), StatusCode::FORBIDDEN);
+        assert_eq!(err.resp_message(), "forbidden");
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("locked")]
+    struct Locked;
+
+    #[test]
+    fn test_register_again() {
+        AnyRespError::register_with::<Locked, _>(|_| (StatusCode::LOCKED, "locked".into()));
+        AnyRespError::register_with::<Locked, _>(|_| (StatusCode::CONFLICT, "conflict".into()));
+        assert!(REGISTRY
+            .read()
+            .unwrap()
+            .contains_key(&TypeId::of::<Locked>()));
+
+        let err = AnyRespError::from(anyhow::Error::from(Locked));
+        assert_eq!(err.http_code(), StatusCode::CONFLICT);
+        assert_eq!(err.resp_message(), "conflict");
+    }
+}
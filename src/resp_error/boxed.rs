@@ -0,0 +1,208 @@
+use std::{borrow::Cow, error::Error as StdError};
+
+use http::StatusCode;
+
+use crate::MessageKey;
+
+use super::{ErrorDetail, RespError};
+
+/// the object-safe companion of [`RespError`], implemented for every [`RespError`]
+///
+/// the extra message is erased into [`serde_json::Value`],
+/// and the `resp_message_default` / `extra_message_default` without `self` is not available
+pub trait DynRespError {
+    /// see [`RespError::log_message`]
+    fn dyn_log_message(&self) -> Cow<'_, str>;
+    /// see [`RespError::resp_message`]
+    fn dyn_resp_message(&self) -> Cow<'_, str>;
+    /// see [`RespError::resp_message_key`]
+    fn dyn_resp_message_key(&self) -> Option<MessageKey<'_>>;
+    /// see [`RespError::resp_details`]
+    fn dyn_resp_details(&self) -> Option<Cow<'_, [ErrorDetail]>>;
+    /// see [`RespError::http_code`]
+    fn dyn_http_code(&self) -> StatusCode;
+    /// see [`RespError::grpc_code`]
+    #[cfg(feature = "tonic")]
+    fn dyn_grpc_code(&self) -> tonic::Code;
+    /// see [`RespError::extra_message`]
+    #[cfg(feature = "extra-error")]
+    fn dyn_extra_message(&self) -> serde_json::Value;
+}
+
+impl<E: RespError> DynRespError for E {
+    #[inline]
+    fn dyn_log_message(&self) -> Cow<'_, str> {
+        self.log_message()
+    }
+    #[inline]
+    fn dyn_resp_message(&self) -> Cow<'_, str> {
+        self.resp_message()
+    }
+    #[inline]
+    fn dyn_resp_message_key(&self) -> Option<MessageKey<'_>> {
+        self.resp_message_key()
+    }
+    #[inline]
+    fn dyn_resp_details(&self) -> Option<Cow<'_, [ErrorDetail]>> {
+        self.resp_details()
+    }
+    #[inline]
+    fn dyn_http_code(&self) -> StatusCode {
+        self.http_code()
+    }
+    #[cfg(feature = "tonic")]
+    #[inline]
+    fn dyn_grpc_code(&self) -> tonic::Code {
+        self.grpc_code()
+    }
+    #[cfg(feature = "extra-error")]
+    #[inline]
+    fn dyn_extra_message(&self) -> serde_json::Value {
+        serde_json::to_value(self.extra_message()).unwrap_or_default()
+    }
+}
+
+/// the type-erased [`RespError`]
+///
+/// any [`RespError`] implemented [`std::error::Error`] can convert into it by [`From`],
+/// otherwise using [`BoxRespError::new`]
+///
+/// # Example
+///
+/// ```rust
+/// use axum_resp_result::{BoxRespError, NotFound, RespError, ValidationErrors};
+/// use http::StatusCode;
+///
+/// fn find(id: u32) -> Result<u32, BoxRespError> {
+///     if id == 0 {
+///         Err(ValidationErrors::new().with("id", "range", "id must great then 0"))?
+///     }
+///     Err(NotFound)?
+/// }
+///
+/// assert_eq!(find(0).unwrap_err().http_code(), StatusCode::UNPROCESSABLE_ENTITY);
+/// assert_eq!(find(1).unwrap_err().http_code(), StatusCode::NOT_FOUND);
+/// ```
+pub struct BoxRespError(Box<dyn DynRespError + Send + Sync>);
+
+impl BoxRespError {
+    /// erase the type of `err`
+    pub fn new<E>(err: E) -> Self
+    where
+        E: RespError + Send + Sync + 'static,
+    {
+        Self(Box::new(err))
+    }
+
+    /// the inner type-erased error
+    pub fn inner(&self) -> &(dyn DynRespError + Send + Sync) {
+        &*self.0
+    }
+}
+
+impl<E> From<E> for BoxRespError
+where
+    E: RespError + StdError + Send + Sync + 'static,
+{
+    #[inline]
+    fn from(err: E) -> Self {
+        Self::new(err)
+    }
+}
+
+impl std::fmt::Debug for BoxRespError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("BoxRespError")
+            .field(&self.0.dyn_log_message())
+            .finish()
+    }
+}
+
+impl RespError for BoxRespError {
+    #[inline]
+    fn log_message(&self) -> Cow<'_, str> {
+        self.0.dyn_log_message()
+    }
+
+    #[inline]
+    fn resp_message(&self) -> Cow<'_, str> {
+        self.0.dyn_resp_message()
+    }
+
+    #[inline]
+    fn resp_message_key(&self) -> Option<MessageKey<'_>> {
+        self.0.dyn_resp_message_key()
+    }
+
+    #[inline]
+    fn resp_details(&self) -> Option<Cow<'_, [ErrorDetail]>> {
+        self.0.dyn_resp_details()
+    }
+
+    #[inline]
+    fn http_code(&self) -> StatusCode {
+        self.0.dyn_http_code()
+    }
+
+    #[cfg(feature = "tonic")]
+    #[inline]
+    fn grpc_code(&self) -> tonic::Code {
+        self.0.dyn_grpc_code()
+    }
+
+    #[cfg(feature = "extra-error")]
+    type ExtraMessage = serde_json::Value;
+
+    #[cfg(feature = "extra-error")]
+    #[inline]
+    fn extra_message(&self) -> Self::ExtraMessage {
+        self.0.dyn_extra_message()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use http::StatusCode;
+
+    use crate::{RespError, ValidationErrors};
+
+    use super::BoxRespError;
+
+    struct PlainError;
+
+    impl RespError for PlainError {
+        fn log_message(&self) -> std::borrow::Cow<'_, str> {
+            "Plain Error".into()
+        }
+
+        fn resp_message(&self) -> std::borrow::Cow<'_, str> {
+            "Plain".into()
+        }
+
+        fn http_code(&self) -> StatusCode {
+            StatusCode::BAD_REQUEST
+        }
+
+        #[cfg(feature = "extra-error")]
+        type ExtraMessage = u32;
+
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Self::ExtraMessage {
+            11
+        }
+    }
+
+    #[test]
+    fn test_erase() {
+        let err = BoxRespError::new(PlainError);
+        assert_eq!(err.log_message(), "Plain Error");
+        assert_eq!(err.resp_message(), "Plain");
+        assert_eq!(err.http_code(), StatusCode::BAD_REQUEST);
+        #[cfg(feature = "extra-error")]
+        assert_eq!(err.extra_message(), serde_json::json!(11));
+
+        let err = BoxRespError::from(ValidationErrors::new().with("a", "b", "c"));
+        assert_eq!(err.http_code(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(err.resp_details().map(|d| d.len()), Some(1));
+    }
+}
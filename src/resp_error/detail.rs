@@ -0,0 +1,46 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+
+/// the structured detail of an error, like a field-level validation error
+///
+/// the details will be serialized into the field set by [`SerdeConfig::details_name`](crate::SerdeConfig::details_name)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ErrorDetail {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    field: Option<Cow<'static, str>>,
+    code: Cow<'static, str>,
+    message: Cow<'static, str>,
+}
+
+impl ErrorDetail {
+    /// create a new [`ErrorDetail`] with provide `code` and `message`
+    pub fn new(code: impl Into<Cow<'static, str>>, message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            field: None,
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+
+    /// set the field this [`ErrorDetail`] belongs to
+    pub fn with_field(mut self, field: impl Into<Cow<'static, str>>) -> Self {
+        self.field = Some(field.into());
+        self
+    }
+
+    /// the field this [`ErrorDetail`] belongs to
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+
+    /// the code of this [`ErrorDetail`]
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// the message of this [`ErrorDetail`]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
@@ -1,5 +1,21 @@
+#[cfg(feature = "anyhow")]
+mod any_error;
+mod boxed;
+mod detail;
+mod not_found;
+mod validation;
+
 use std::borrow::Cow;
 
+use crate::MessageKey;
+
+#[cfg(feature = "anyhow")]
+pub use self::any_error::AnyRespError;
+pub use self::boxed::{BoxRespError, DynRespError};
+pub use self::detail::ErrorDetail;
+pub use self::not_found::NotFound;
+pub use self::validation::ValidationErrors;
+
 /// the error when [`RespResult`](crate::RespResult) is `Err(_)`
 pub trait RespError {
     /// message for logger
@@ -13,6 +29,26 @@ pub trait RespError {
         self.log_message()
     }
 
+    /// the key of response message, for translating the response message
+    /// by the [`Translator`](crate::Translator) set in [`SerdeConfig::translator`](crate::SerdeConfig::translator)
+    ///
+    /// ## Default
+    /// the default is `None`, the [`RespError::resp_message`] will be used without translating
+    #[inline]
+    fn resp_message_key(&self) -> Option<MessageKey<'_>> {
+        None
+    }
+
+    /// the structured details of this error, like the field-level validation errors,
+    /// it will be serialized into the field set by [`SerdeConfig::details_name`](crate::SerdeConfig::details_name)
+    ///
+    /// ## Default
+    /// the default is `None`, it will be serialized to `null`
+    #[inline]
+    fn resp_details(&self) -> Option<Cow<'_, [ErrorDetail]>> {
+        None
+    }
+
     /// the http code of this error
     ///
     /// ## Default
@@ -22,6 +58,18 @@ pub trait RespError {
         http::StatusCode::INTERNAL_SERVER_ERROR
     }
 
+    /// the gRPC code of this error, using when converting into [`tonic::Status`]
+    /// by [`RespResult::into_tonic`](crate::RespResult::into_tonic)
+    ///
+    /// ## Default
+    /// the default is the closest gRPC code of [`RespError::http_code`],
+    /// see [`grpc_code_from_http`](crate::grpc_code_from_http)
+    #[cfg(feature = "tonic")]
+    #[inline]
+    fn grpc_code(&self) -> tonic::Code {
+        crate::grpc_code_from_http(self.http_code())
+    }
+
     #[cfg(feature = "extra-error")]
     /// the associate type of extra message
     type ExtraMessage: serde::Serialize + 'static + Sized + std::fmt::Display;
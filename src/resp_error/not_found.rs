@@ -0,0 +1,37 @@
+use std::{borrow::Cow, fmt::Display};
+
+use http::StatusCode;
+
+use super::RespError;
+
+/// the prefab [`RespError`] with `404 Not Found`
+///
+/// it is the failure when the [`Fallible`](crate::Fallible) [`Option`] is `None`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NotFound;
+
+impl Display for NotFound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Not Found")
+    }
+}
+
+impl std::error::Error for NotFound {}
+
+impl RespError for NotFound {
+    fn log_message(&self) -> Cow<'_, str> {
+        "Not Found".into()
+    }
+
+    fn http_code(&self) -> StatusCode {
+        StatusCode::NOT_FOUND
+    }
+
+    #[cfg(feature = "extra-error")]
+    type ExtraMessage = crate::Nil;
+
+    #[cfg(feature = "extra-error")]
+    fn extra_message(&self) -> Self::ExtraMessage {
+        crate::Nil
+    }
+}
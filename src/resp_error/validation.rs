@@ -0,0 +1,147 @@
+use std::borrow::Cow;
+
+use http::StatusCode;
+
+use super::{ErrorDetail, RespError};
+
+/// a set of field-level validation errors
+///
+/// it implements [`RespError`] with `422 Unprocessable Entity`,
+/// and all the errors will be serialized as [details](crate::SerdeConfig::details_name)
+///
+/// # Example
+///
+/// ```rust
+/// use axum_resp_result::ValidationErrors;
+///
+/// let mut errors = ValidationErrors::new();
+/// errors.add("email", "invalid", "email is not valid");
+/// errors.add("age", "range", "age must great then 18");
+///
+/// assert_eq!(errors.len(), 2);
+/// assert!(errors.into_result().is_err());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ValidationErrors {
+    details: Vec<ErrorDetail>,
+}
+
+impl ValidationErrors {
+    /// create an empty [`ValidationErrors`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// adding an error of `field`
+    pub fn add(
+        &mut self,
+        field: impl Into<Cow<'static, str>>,
+        code: impl Into<Cow<'static, str>>,
+        message: impl Into<Cow<'static, str>>,
+    ) {
+        self.details
+            .push(ErrorDetail::new(code, message).with_field(field));
+    }
+
+    /// adding an error of `field`, then return the [`ValidationErrors`]
+    pub fn with(
+        mut self,
+        field: impl Into<Cow<'static, str>>,
+        code: impl Into<Cow<'static, str>>,
+        message: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.add(field, code, message);
+        self
+    }
+
+    /// adding an [`ErrorDetail`]
+    pub fn push(&mut self, detail: ErrorDetail) {
+        self.details.push(detail);
+    }
+
+    /// moving all errors of `other` into this
+    pub fn merge(&mut self, other: ValidationErrors) {
+        self.details.extend(other.details);
+    }
+
+    /// whether there is no error
+    pub fn is_empty(&self) -> bool {
+        self.details.is_empty()
+    }
+
+    /// the number of errors
+    pub fn len(&self) -> usize {
+        self.details.len()
+    }
+
+    /// all the errors
+    pub fn details(&self) -> &[ErrorDetail] {
+        &self.details
+    }
+
+    /// - `Ok(())` if there is no error
+    /// - `Err(self)` otherwise
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl Extend<ErrorDetail> for ValidationErrors {
+    fn extend<I: IntoIterator<Item = ErrorDetail>>(&mut self, iter: I) {
+        self.details.extend(iter)
+    }
+}
+
+impl FromIterator<ErrorDetail> for ValidationErrors {
+    fn from_iter<I: IntoIterator<Item = ErrorDetail>>(iter: I) -> Self {
+        Self {
+            details: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Validation Failed")?;
+        for (idx, detail) in self.details.iter().enumerate() {
+            let sep = if idx == 0 { ": " } else { ", " };
+            match detail.field() {
+                Some(field) => write!(f, "{sep}[{field}] {}", detail.message())?,
+                None => write!(f, "{sep}{}", detail.message())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+impl RespError for ValidationErrors {
+    fn log_message(&self) -> Cow<'_, str> {
+        self.to_string().into()
+    }
+
+    fn resp_message(&self) -> Cow<'_, str> {
+        "Validation Failed".into()
+    }
+
+    fn http_code(&self) -> StatusCode {
+        StatusCode::UNPROCESSABLE_ENTITY
+    }
+
+    fn resp_details(&self) -> Option<Cow<'_, [ErrorDetail]>> {
+        Some(Cow::Borrowed(&self.details))
+    }
+
+    #[cfg(feature = "extra-error")]
+    type ExtraMessage = crate::Nil;
+
+    #[cfg(feature = "extra-error")]
+    fn extra_message(&self) -> Self::ExtraMessage {
+        crate::Nil
+    }
+}
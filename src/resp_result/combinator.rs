@@ -0,0 +1,250 @@
+use std::future::Future;
+
+use super::RespResult;
+
+impl<T, E> RespResult<T, E> {
+    #[inline]
+    /// whether this is [`RespResult::Success`]
+    pub fn is_success(&self) -> bool {
+        matches!(self, RespResult::Success(_))
+    }
+
+    #[inline]
+    /// whether this is [`RespResult::Err`]
+    pub fn is_err(&self) -> bool {
+        matches!(self, RespResult::Err(_))
+    }
+
+    #[inline]
+    /// converts from `&RespResult<T, E>` to `RespResult<&T, &E>`
+    pub fn as_ref(&self) -> RespResult<&T, &E> {
+        match self {
+            RespResult::Success(data) => RespResult::Success(data),
+            RespResult::Err(e) => RespResult::Err(e),
+        }
+    }
+
+    #[inline]
+    /// converts from `&mut RespResult<T, E>` to `RespResult<&mut T, &mut E>`
+    pub fn as_mut(&mut self) -> RespResult<&mut T, &mut E> {
+        match self {
+            RespResult::Success(data) => RespResult::Success(data),
+            RespResult::Err(e) => RespResult::Err(e),
+        }
+    }
+
+    #[inline]
+    /// get the success data, discarding the error
+    ///
+    /// this method is similar to [`Result::ok`], the name `ok` is taken by the constructor [`RespResult::ok`]
+    pub fn success(self) -> Option<T> {
+        match self {
+            RespResult::Success(data) => Some(data),
+            RespResult::Err(_) => None,
+        }
+    }
+
+    #[inline]
+    /// get the error, discarding the success data
+    ///
+    /// this method is similar to [`Result::err`], the name `err` is taken by the constructor [`RespResult::err`]
+    pub fn error(self) -> Option<E> {
+        match self {
+            RespResult::Success(_) => None,
+            RespResult::Err(e) => Some(e),
+        }
+    }
+
+    #[inline]
+    /// convert into [`Result`]
+    pub fn into_result(self) -> Result<T, E> {
+        match self {
+            RespResult::Success(data) => Ok(data),
+            RespResult::Err(e) => Err(e),
+        }
+    }
+
+    #[inline]
+    /// this method is similar to the same name method of [`Result`]
+    pub fn unwrap_or_else<F>(self, f: F) -> T
+    where
+        F: FnOnce(E) -> T,
+    {
+        match self {
+            RespResult::Success(data) => data,
+            RespResult::Err(e) => f(e),
+        }
+    }
+
+    #[inline]
+    /// this method is similar to the same name method of [`Result`]
+    pub fn inspect<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&T),
+    {
+        if let RespResult::Success(data) = &self {
+            f(data)
+        }
+        self
+    }
+
+    #[inline]
+    /// this method is similar to the same name method of [`Result`]
+    pub fn inspect_err<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&E),
+    {
+        if let RespResult::Err(e) = &self {
+            f(e)
+        }
+        self
+    }
+
+    #[inline]
+    /// this method is similar to the same name method of [`Result`]
+    pub fn and<N>(self, res: RespResult<N, E>) -> RespResult<N, E> {
+        match self {
+            RespResult::Success(_) => res,
+            RespResult::Err(e) => RespResult::Err(e),
+        }
+    }
+
+    #[inline]
+    /// this method is similar to the same name method of [`Result`]
+    pub fn or<N>(self, res: RespResult<T, N>) -> RespResult<T, N> {
+        match self {
+            RespResult::Success(data) => RespResult::Success(data),
+            RespResult::Err(_) => res,
+        }
+    }
+
+    #[inline]
+    /// combine the success data of `self` and `other` into a tuple,
+    /// the first error will be returned if any
+    pub fn zip<U>(self, other: RespResult<U, E>) -> RespResult<(T, U), E> {
+        self.and_then(|data| other.map(|other| (data, other)))
+    }
+
+    /// async version of [`RespResult::map`]
+    pub async fn map_async<N, F, Fut>(self, f: F) -> RespResult<N, E>
+    where
+        F: FnOnce(T) -> Fut,
+        Fut: Future<Output = N>,
+    {
+        match self {
+            RespResult::Success(data) => RespResult::Success(f(data).await),
+            RespResult::Err(e) => RespResult::Err(e),
+        }
+    }
+
+    /// async version of [`RespResult::and_then`]
+    pub async fn and_then_async<N, F, Fut>(self, f: F) -> RespResult<N, E>
+    where
+        F: FnOnce(T) -> Fut,
+        Fut: Future<Output = RespResult<N, E>>,
+    {
+        match self {
+            RespResult::Success(data) => f(data).await,
+            RespResult::Err(e) => RespResult::Err(e),
+        }
+    }
+}
+
+impl<T, E> RespResult<RespResult<T, E>, E> {
+    #[inline]
+    /// converts from `RespResult<RespResult<T, E>, E>` to `RespResult<T, E>`
+    pub fn flatten(self) -> RespResult<T, E> {
+        self.and_then(|inner| inner)
+    }
+}
+
+impl<T, E> RespResult<Option<T>, E> {
+    #[inline]
+    /// converts from `RespResult<Option<T>, E>` to `Option<RespResult<T, E>>`
+    ///
+    /// this method is similar to [`Result::transpose`]
+    pub fn transpose(self) -> Option<RespResult<T, E>> {
+        match self {
+            RespResult::Success(Some(data)) => Some(RespResult::Success(data)),
+            RespResult::Success(None) => None,
+            RespResult::Err(e) => Some(RespResult::Err(e)),
+        }
+    }
+}
+
+impl<T, E> From<RespResult<T, E>> for Result<T, E> {
+    #[inline]
+    fn from(resp: RespResult<T, E>) -> Self {
+        resp.into_result()
+    }
+}
+
+/// collecting the success data into `C`, stop at the first error
+impl<T, E, C> FromIterator<RespResult<T, E>> for RespResult<C, E>
+where
+    C: FromIterator<T>,
+{
+    fn from_iter<I: IntoIterator<Item = RespResult<T, E>>>(iter: I) -> Self {
+        match iter
+            .into_iter()
+            .map(RespResult::into_result)
+            .collect::<Result<C, E>>()
+        {
+            Ok(data) => RespResult::Success(data),
+            Err(e) => RespResult::Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{NotFound, RespResult};
+
+    #[test]
+    fn test_combinators() {
+        let ok = RespResult::<i32, NotFound>::Success(1);
+        assert!(ok.is_success());
+        assert_eq!(ok.as_ref().success(), Some(&1));
+        assert!(matches!(
+            ok.zip(RespResult::Success("a")),
+            RespResult::Success((1, "a"))
+        ));
+
+        let err = RespResult::<i32, _>::Err(NotFound);
+        assert!(err.is_err());
+        let mut inspected = false;
+        let err = err.inspect_err(|_| inspected = true);
+        assert!(inspected);
+        assert_eq!(err.unwrap_or_else(|_| 0), 0);
+
+        let nested = RespResult::<_, NotFound>::Success(RespResult::Success(Some(2)));
+        assert!(matches!(
+            nested.flatten().transpose(),
+            Some(RespResult::Success(2))
+        ));
+    }
+
+    #[test]
+    fn test_collect() {
+        let collected = [1, 2, 3]
+            .into_iter()
+            .map(RespResult::<_, NotFound>::Success)
+            .collect::<RespResult<Vec<_>, _>>();
+        assert_eq!(collected.success(), Some(vec![1, 2, 3]));
+
+        let collected = [RespResult::Success(1), RespResult::Err(NotFound)]
+            .into_iter()
+            .collect::<RespResult<Vec<_>, _>>();
+        assert!(collected.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_async_combinators() {
+        let resp = RespResult::<_, NotFound>::Success(1)
+            .map_async(|n| async move { n + 1 })
+            .await
+            .and_then_async(|n| async move { RespResult::Success(n * 2) })
+            .await;
+        assert_eq!(resp.into_result().ok(), Some(4));
+    }
+}
@@ -1,12 +1,18 @@
-use serde::{ser::SerializeStruct, Serialize, Serializer};
+use serde::{ser::SerializeMap, Serialize, Serializer};
 #[cfg(feature = "tracing")]
 use {
     std::any::type_name,
-    trace as tracing,
     trace::{event, Level},
 };
 
-use crate::{get_config, resp_body::RespBody, resp_error::RespError};
+use crate::{
+    config::{InnerSerdeConfig, Timestamp},
+    context::RequestContext,
+    get_config,
+    i18n::localized_message,
+    resp_body::{RespBody, WithConfig, WithPageMeta},
+    resp_error::RespError,
+};
 
 use super::RespResult;
 
@@ -37,8 +43,58 @@ where
     where
         S: serde::Serializer,
     {
-        let cfg = &get_config().serde;
+        self.serialize_with_config(&get_config().serde, serializer)
+    }
+}
+
+impl<T, E> RespResult<T, E>
+where
+    T: RespBody,
+    E: RespError,
+{
+    /// serialize with the given config, the public responses always use the global config
+    /// set by [`set_config`](crate::set_config), the other configs are only built inside the crate
+    pub(crate) fn serialize_with_config<S>(
+        &self,
+        cfg: &InnerSerdeConfig,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.serialize_envelope(cfg, true, serializer)
+    }
+
+    /// serialize as an item nested in the outer envelope, like the items of [`BatchResp`](crate::BatchResp),
+    /// the request context fields only appear on the outer envelope
+    pub(crate) fn serialize_item<S>(
+        &self,
+        cfg: &InnerSerdeConfig,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.serialize_envelope(cfg, false, serializer)
+    }
+
+    fn serialize_envelope<S>(
+        &self,
+        cfg: &InnerSerdeConfig,
+        with_context: bool,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let context = RequestContext::current();
         let (ok_size, err_size) = cfg.get_field_size();
+        let (ok_size, err_size) = if with_context {
+            (ok_size, err_size)
+        } else {
+            let context_size = cfg.get_context_size();
+            (ok_size - context_size, err_size - context_size)
+        };
 
         #[cfg(feature = "tracing")]
         event!(
@@ -58,19 +114,51 @@ where
                         type_name::<<T as crate::resp_body::LoadSerde>::SerdeData>()
                 );
 
-                let mut body = serializer.serialize_struct("RespResult", ok_size)?;
+                let page_meta = data.page_meta();
+                // the sibling page metadata is skipped when absent and not full field
+                let ok_size = match (&cfg.page_meta, page_meta) {
+                    (Some(_), Some(_)) if !cfg.full_field => ok_size + 1,
+                    _ => ok_size,
+                };
+                // the warnings only appear when any
+                let warnings = cfg
+                    .warnings
+                    .as_deref()
+                    .filter(|_| !data.warnings().is_empty());
+                let ok_size = ok_size + warnings.is_some() as usize;
+
+                let mut body = serializer.serialize_map(Some(ok_size))?;
                 if let Some(ref signed_status) = cfg.signed_status {
-                    body.serialize_field(signed_status.field, &signed_status.ok)?;
+                    body.serialize_entry(&*signed_status.field, &signed_status.ok)?;
+                }
+                if with_context {
+                    serialize_context(&mut body, cfg, context.as_ref())?;
                 }
                 if cfg.full_field {
                     #[cfg(feature = "extra-error")]
-                    if let Some(ecl) = cfg.extra_code {
-                        body.serialize_field(ecl, &E::extra_message_default())?;
+                    if let Some(ecl) = &cfg.extra_code {
+                        body.serialize_entry(&**ecl, &E::extra_message_default())?;
+                    }
+                    body.serialize_entry(&*cfg.err_msg_name, &E::resp_message_default())?;
+                    if let Some(details) = &cfg.details {
+                        body.serialize_entry(&**details, &())?;
                     }
-                    body.serialize_field(cfg.err_msg_name, &E::resp_message_default())?;
                 }
 
-                body.serialize_field(cfg.body_name, data.load_serde())?;
+                if let Some(warnings) = warnings {
+                    body.serialize_entry(warnings, data.warnings())?;
+                }
+                match (&cfg.page_meta, page_meta) {
+                    (Some(meta_name), meta) if cfg.full_field || meta.is_some() => {
+                        body.serialize_entry(&**meta_name, &meta)?;
+                        body.serialize_entry(&*cfg.body_name, &WithConfig(data, cfg))?;
+                    }
+                    (None, Some(meta)) => body.serialize_entry(
+                        &*cfg.body_name,
+                        &WithPageMeta(&WithConfig(data, cfg), meta),
+                    )?,
+                    _ => body.serialize_entry(&*cfg.body_name, &WithConfig(data, cfg))?,
+                }
 
                 body.end()?
             }
@@ -82,19 +170,31 @@ where
                     "error.type" = type_name::<E>(),
                     error = %err.log_message()
                 );
-                let mut body = serializer.serialize_struct("RespResult", err_size)?;
+                let mut body = serializer.serialize_map(Some(err_size))?;
 
                 if let Some(ref status_sign) = cfg.signed_status {
-                    body.serialize_field(status_sign.field, &status_sign.err)?;
+                    body.serialize_entry(&*status_sign.field, &status_sign.err)?;
+                }
+                if with_context {
+                    serialize_context(&mut body, cfg, context.as_ref())?;
                 }
                 #[cfg(feature = "extra-error")]
-                if let Some(ecl) = cfg.extra_code {
-                    body.serialize_field(ecl, &err.extra_message())?;
+                if let Some(ecl) = &cfg.extra_code {
+                    body.serialize_entry(&**ecl, &err.extra_message())?;
+                }
+                body.serialize_entry(
+                    &*cfg.err_msg_name,
+                    &localized_message(cfg, err, context.as_ref()),
+                )?;
+                if let Some(details) = &cfg.details {
+                    body.serialize_entry(&**details, &err.resp_details())?;
                 }
-                body.serialize_field(cfg.err_msg_name, &err.resp_message())?;
 
                 if cfg.full_field {
-                    body.serialize_field(cfg.body_name, &())?;
+                    if let Some(meta_name) = &cfg.page_meta {
+                        body.serialize_entry(&**meta_name, &())?;
+                    }
+                    body.serialize_entry(&*cfg.body_name, &())?;
                 }
                 body.end()?
             }
@@ -102,3 +202,361 @@ where
         Ok(resp)
     }
 }
+
+fn serialize_context<B: SerializeMap>(
+    body: &mut B,
+    cfg: &InnerSerdeConfig,
+    context: Option<&RequestContext>,
+) -> Result<(), B::Error> {
+    if let Some(request_id) = &cfg.request_id {
+        body.serialize_entry(&**request_id, &context.map(RequestContext::request_id))?;
+    }
+    if let Some(trace_id) = &cfg.trace_id {
+        body.serialize_entry(&**trace_id, &context.and_then(RequestContext::trace_id))?;
+    }
+    if let Some((timestamp, format)) = &cfg.timestamp {
+        body.serialize_entry(&**timestamp, &Timestamp::now(*format))?;
+    }
+    if let Some(elapsed) = &cfg.elapsed {
+        body.serialize_entry(
+            &**elapsed,
+            &context.map(|ctx| ctx.elapsed().as_secs_f64() * 1000.0),
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{borrow::Cow, sync::Arc};
+
+    use serde_json::json;
+
+    use crate::{
+        config::InnerSerdeConfig, Catalog, MessageKey, Paged, RespError, RespResult, SerdeConfig,
+        TimestampFormat, Translator, ValidationErrors, Warning,
+    };
+
+    struct MockErr;
+
+    impl RespError for MockErr {
+        fn log_message(&self) -> Cow<'_, str> {
+            "Mock Error".into()
+        }
+        #[cfg(feature = "extra-error")]
+        type ExtraMessage = String;
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Self::ExtraMessage {
+            "Mock".into()
+        }
+    }
+
+    #[cfg(feature = "context")]
+    struct ContextConfig;
+
+    #[cfg(feature = "context")]
+    impl SerdeConfig for ContextConfig {
+        fn signed_status(&self) -> Option<crate::StatusSign> {
+            None
+        }
+
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Option<Cow<'static, str>> {
+            None
+        }
+
+        fn request_id_name(&self) -> Option<Cow<'static, str>> {
+            Some("request-id".into())
+        }
+
+        fn trace_id_name(&self) -> Option<Cow<'static, str>> {
+            Some("trace-id".into())
+        }
+    }
+
+    fn to_json<T: crate::resp_body::RespBody>(
+        cfg: &InnerSerdeConfig,
+        resp: &RespResult<T, MockErr>,
+    ) -> serde_json::Value {
+        let mut buf = Vec::new();
+        resp.serialize_with_config(cfg, &mut serde_json::Serializer::new(&mut buf))
+            .unwrap();
+        serde_json::from_slice(&buf).unwrap()
+    }
+
+    #[cfg(feature = "context")]
+    #[tokio::test]
+    async fn test_context_field() {
+        let cfg = InnerSerdeConfig::into_inner(&ContextConfig);
+
+        let value = crate::RequestContext::new("req-1")
+            .scope(async { to_json(&cfg, &RespResult::Success(1)) })
+            .await;
+        assert_eq!(
+            value,
+            json!({"request-id": "req-1", "trace-id": null, "error-message": null, "body": 1})
+        );
+
+        let value = to_json(&cfg, &RespResult::<i32, _>::Err(MockErr));
+        assert_eq!(
+            value,
+            json!({"request-id": null, "trace-id": null, "error-message": "Mock Error", "body": null})
+        );
+    }
+
+    struct TimeConfig(TimestampFormat);
+
+    impl SerdeConfig for TimeConfig {
+        fn timestamp_name(&self) -> Option<Cow<'static, str>> {
+            Some("timestamp".into())
+        }
+
+        fn timestamp_format(&self) -> TimestampFormat {
+            self.0
+        }
+
+        fn elapsed_name(&self) -> Option<Cow<'static, str>> {
+            Some("elapsed".into())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_time_field() {
+        let cfg = InnerSerdeConfig::into_inner(&TimeConfig(TimestampFormat::UnixMillis));
+
+        let value = to_json(&cfg, &RespResult::Success(1));
+        assert!(value["timestamp"].is_u64());
+        #[cfg(feature = "context")]
+        {
+            let value = crate::RequestContext::new("req-1")
+                .scope(async { to_json(&cfg, &RespResult::Success(1)) })
+                .await;
+            assert!(value["elapsed"].is_f64());
+        }
+
+        let cfg = InnerSerdeConfig::into_inner(&TimeConfig(TimestampFormat::Rfc3339));
+        let value = to_json(&cfg, &RespResult::<i32, _>::Err(MockErr));
+        let timestamp = value["timestamp"].as_str().unwrap();
+        assert_eq!(timestamp.len(), "2018-02-14T00:28:07.123Z".len());
+        assert!(timestamp.ends_with('Z'));
+        assert!(value["elapsed"].is_null());
+    }
+
+    struct KeyErr;
+
+    impl RespError for KeyErr {
+        fn log_message(&self) -> Cow<'_, str> {
+            "Key Error".into()
+        }
+
+        fn resp_message_key(&self) -> Option<MessageKey<'_>> {
+            Some(MessageKey::new("user.not_found").arg("id", "42"))
+        }
+        #[cfg(feature = "extra-error")]
+        type ExtraMessage = String;
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Self::ExtraMessage {
+            "Key".into()
+        }
+    }
+
+    struct I18nConfig;
+
+    impl SerdeConfig for I18nConfig {
+        fn translator(&self) -> Option<Arc<dyn Translator>> {
+            Some(Arc::new(
+                Catalog::new()
+                    .with("en", "user.not_found", "user {id} not found")
+                    .with("fr", "user.not_found", "utilisateur {id} introuvable"),
+            ))
+        }
+
+        fn fallback_locale(&self) -> Option<Cow<'static, str>> {
+            Some("en".into())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_localized_message() {
+        let cfg = InnerSerdeConfig::into_inner(&I18nConfig);
+        let resp = RespResult::<i32, _>::Err(KeyErr);
+
+        #[cfg(feature = "context")]
+        {
+            let mut buf = Vec::new();
+            let value = crate::RequestContext::new("req-1")
+                .with_locales(["fr-CH"])
+                .scope(async {
+                    resp.serialize_with_config(&cfg, &mut serde_json::Serializer::new(&mut buf))
+                })
+                .await;
+            value.unwrap();
+            let value: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+            assert_eq!(value["error-message"], "utilisateur 42 introuvable");
+        }
+
+        let mut buf = Vec::new();
+        resp.serialize_with_config(&cfg, &mut serde_json::Serializer::new(&mut buf))
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(value["error-message"], "user 42 not found");
+
+        let value = to_json(&cfg, &RespResult::<i32, _>::Err(MockErr));
+        assert_eq!(value["error-message"], "Mock Error");
+    }
+
+    struct DetailsConfig;
+
+    impl SerdeConfig for DetailsConfig {
+        fn signed_status(&self) -> Option<crate::StatusSign> {
+            None
+        }
+
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Option<Cow<'static, str>> {
+            None
+        }
+
+        fn details_name(&self) -> Option<Cow<'static, str>> {
+            Some("details".into())
+        }
+    }
+
+    #[test]
+    fn test_details_field() {
+        let cfg = InnerSerdeConfig::into_inner(&DetailsConfig);
+
+        let resp = RespResult::<_, ValidationErrors>::Success(1);
+        let mut buf = Vec::new();
+        resp.serialize_with_config(&cfg, &mut serde_json::Serializer::new(&mut buf))
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(
+            value,
+            json!({"error-message": null, "details": null, "body": 1})
+        );
+
+        let resp = RespResult::<i32, _>::Err(ValidationErrors::new().with(
+            "email",
+            "invalid",
+            "email is not valid",
+        ));
+        let mut buf = Vec::new();
+        resp.serialize_with_config(&cfg, &mut serde_json::Serializer::new(&mut buf))
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "error-message": "Validation Failed",
+                "details": [{"field": "email", "code": "invalid", "message": "email is not valid"}],
+                "body": null
+            })
+        );
+
+        let value = to_json(&cfg, &RespResult::<i32, _>::Err(MockErr));
+        assert_eq!(value["details"], serde_json::Value::Null);
+    }
+
+    struct PageConfig;
+
+    impl SerdeConfig for PageConfig {
+        fn signed_status(&self) -> Option<crate::StatusSign> {
+            None
+        }
+
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Option<Cow<'static, str>> {
+            None
+        }
+
+        fn page_meta_name(&self) -> Option<Cow<'static, str>> {
+            Some("meta".into())
+        }
+    }
+
+    #[test]
+    fn test_page_meta() {
+        let page = Paged::new(vec![1, 2]).with_page(1, 2).with_total(4);
+
+        let cfg = InnerSerdeConfig::into_inner(&DetailsConfig);
+        let value = to_json(&cfg, &RespResult::Success(page.clone()));
+        assert_eq!(
+            value["body"],
+            json!({"items": [1, 2], "total": 4, "page": 1, "page_size": 2, "next_cursor": null})
+        );
+
+        let cfg = InnerSerdeConfig::into_inner(&PageConfig);
+        let value = to_json(&cfg, &RespResult::Success(page));
+        assert_eq!(
+            value,
+            json!({
+                "error-message": null,
+                "meta": {"total": 4, "page": 1, "page_size": 2, "next_cursor": null},
+                "body": [1, 2]
+            })
+        );
+
+        let value = to_json(&cfg, &RespResult::Success(1));
+        assert_eq!(
+            value,
+            json!({"error-message": null, "meta": null, "body": 1})
+        );
+
+        let value = to_json(&cfg, &RespResult::<i32, _>::Err(MockErr));
+        assert_eq!(
+            value,
+            json!({"error-message": "Mock Error", "meta": null, "body": null})
+        );
+    }
+
+    #[test]
+    fn test_warnings_field() {
+        let cfg = InnerSerdeConfig::into_inner(&DetailsConfig);
+
+        let resp = RespResult::<_, MockErr>::Success(1)
+            .with_warnings([Warning::new("deprecated", "`sort` is deprecated")]);
+        let value = to_json(&cfg, &resp);
+        assert_eq!(
+            value,
+            json!({
+                "error-message": null,
+                "details": null,
+                "warnings": [{"code": "deprecated", "message": "`sort` is deprecated"}],
+                "body": 1
+            })
+        );
+
+        let resp = RespResult::<_, MockErr>::Success(1).with_warnings([]);
+        let value = to_json(&cfg, &resp);
+        assert_eq!(
+            value,
+            json!({"error-message": null, "details": null, "body": 1})
+        );
+    }
+
+    struct OwnedConfig(usize);
+
+    impl SerdeConfig for OwnedConfig {
+        fn body_name(&self) -> Cow<'static, str> {
+            format!("body-{}", self.0).into()
+        }
+
+        fn signed_status(&self) -> Option<crate::StatusSign> {
+            Some(crate::StatusSign::new(
+                format!("ok-{}", self.0),
+                crate::SignType::new_bool(),
+            ))
+        }
+    }
+
+    #[test]
+    fn test_owned_field_name() {
+        for idx in 0..3 {
+            let cfg = InnerSerdeConfig::into_inner(&OwnedConfig(idx));
+            let value = to_json(&cfg, &RespResult::Success(idx));
+            assert_eq!(value[format!("body-{idx}")], idx);
+            assert_eq!(value[format!("ok-{idx}")], true);
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use actix_web::{
+    body::BoxBody,
+    http::{
+        header::{HeaderName, HeaderValue},
+        StatusCode,
+    },
+    HttpRequest, HttpResponse, Responder,
+};
+
+use crate::{expect_ext::ExpectExt, resp_body::RespBody, RespError, RespResult};
+
+use super::PrepareRespond;
+
+impl<T, E> Responder for RespResult<T, E>
+where
+    T: RespBody,
+    E: RespError,
+{
+    type Body = BoxBody;
+
+    #[inline]
+    #[cfg_attr(
+        feature = "tracing",
+        trace::instrument(name = "actix-respond-to", skip_all)
+    )]
+    fn respond_to(self, _: &HttpRequest) -> HttpResponse<Self::Body> {
+        actix_response(&self)
+    }
+}
+
+/// build the actix-web response of `resp`
+pub(crate) fn actix_response<T, E>(resp: &RespResult<T, E>) -> HttpResponse
+where
+    T: RespBody,
+    E: RespError,
+{
+    PrepareRespond::from_resp_result(resp).into_actix()
+}
+
+impl PrepareRespond {
+    /// convert into the actix-web response, the `http` types of actix-web is in different version
+    fn into_actix(self) -> HttpResponse {
+        let status = StatusCode::from_u16(self.status.as_u16()).with_expect("Bad StatusCode");
+        let mut builder = HttpResponse::build(status);
+        for (name, value) in &self.headers {
+            builder.append_header((
+                HeaderName::from_bytes(name.as_ref()).with_expect("Bad Header Name"),
+                HeaderValue::from_bytes(value.as_bytes()).with_expect("Bad Header Value"),
+            ));
+        }
+        builder.body(self.body)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::{body::to_bytes, http::StatusCode, test::TestRequest, Responder};
+
+    use crate::{ExtraFlag, RespError, RespResult};
+
+    struct MockErr;
+
+    impl RespError for MockErr {
+        fn log_message(&self) -> std::borrow::Cow<'_, str> {
+            "Mock Error".into()
+        }
+
+        fn http_code(&self) -> http::StatusCode {
+            http::StatusCode::BAD_REQUEST
+        }
+
+        #[cfg(feature = "extra-error")]
+        type ExtraMessage = String;
+
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Self::ExtraMessage {
+            "Mock".into()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_responder() {
+        let req = TestRequest::default().to_http_request();
+
+        let resp = RespResult::<_, MockErr>::Success(12)
+            .with_flags(ExtraFlag::insert_header("x-foo", "bar"))
+            .respond_to(&req);
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("x-foo").unwrap(), "bar");
+        assert_eq!(
+            resp.headers().get("content-type").unwrap(),
+            "application/json"
+        );
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        assert!(body.starts_with(b"{"));
+
+        let resp = RespResult::<i32, _>::Err(MockErr).respond_to(&req);
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -1,6 +1,4 @@
 use axum;
-#[cfg(feature = "tracing")]
-use trace as tracing;
 impl<T, E> axum::response::IntoResponse for crate::RespResult<T, E>
 where
     T: crate::resp_body::RespBody,
@@ -12,18 +10,7 @@ where
         trace::instrument(name = "axum-into-response", skip_all)
     )]
     fn into_response(self) -> axum::response::Response {
-        use crate::expect_ext::ExpectExt;
-
-        let respond = super::PrepareRespond::from_resp_result(&self);
-        let mut builder = axum::response::Response::builder().status(respond.status);
-
-        builder
-            .headers_mut()
-            .with_expect("RespResult 构造响应时发生异常")
-            .extend(respond.headers);
-        builder
-            .body(axum::body::Body::from(respond.body))
-            .with_expect("RespResult 构造响应时发生异常")
+        self.into_http_response().map(axum::body::Body::from)
     }
 }
 pub mod axum_respond_part {
@@ -0,0 +1,209 @@
+use std::borrow::Cow;
+
+use async_graphql::{
+    parser::types::Field, registry::Registry, to_value, ContextSelectionSet, ErrorExtensionValues,
+    OutputType, Positioned, ServerResult, Value,
+};
+#[cfg(feature = "tracing")]
+use trace::{event, Level};
+
+use crate::{
+    config::InnerConfig, context::RequestContext, get_config, i18n::localized_message, RespError,
+    RespResult,
+};
+
+/// the resolver can return [`RespResult`] directly,
+/// the `Err(_)` will be the GraphQL error built by [`graphql_error`]
+impl<T, E> OutputType for RespResult<T, E>
+where
+    T: OutputType,
+    E: RespError + Send + Sync,
+{
+    fn type_name() -> Cow<'static, str> {
+        T::type_name()
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        T::create_type_info(registry)
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        match self {
+            RespResult::Success(value) => value.resolve(ctx, field).await,
+            RespResult::Err(err) => {
+                Err(ctx.set_error_path(graphql_error(err).into_server_error(field.pos)))
+            }
+        }
+    }
+}
+
+impl<T, E: RespError> RespResult<T, E> {
+    /// convert into the result of [async-graphql](https://docs.rs/async-graphql),
+    /// the `Err(_)` will be the GraphQL error built by [`graphql_error`]
+    pub fn into_graphql(self) -> async_graphql::Result<T> {
+        match self {
+            RespResult::Success(data) => Ok(data),
+            RespResult::Err(err) => Err(graphql_error(&err)),
+        }
+    }
+}
+
+/// build the GraphQL error of `err`, with the same semantics as the response body
+///
+/// - the message is the response message, translated if possible
+/// - the extension `status` is the http status code
+/// - the extra message, details, request id and trace id are the extensions
+///   with the field names set by [`SerdeConfig`](crate::SerdeConfig), if enabled
+pub fn graphql_error<E: RespError>(err: &E) -> async_graphql::Error {
+    graphql_error_with_config(err, get_config())
+}
+
+fn graphql_error_with_config<E: RespError>(err: &E, cfg: &InnerConfig) -> async_graphql::Error {
+    #[cfg(feature = "tracing")]
+    event!(
+        Level::WARN,
+        result = "RespResult::Err",
+        status = %err.http_code(),
+        error = %err.log_message()
+    );
+    let context = RequestContext::current();
+    let mut extensions = ErrorExtensionValues::default();
+
+    extensions.set("status", err.http_code().as_u16());
+    #[cfg(feature = "extra-error")]
+    if let Some(name) = &cfg.serde.extra_code {
+        extensions.set(name, to_value(err.extra_message()).unwrap_or_default());
+    }
+    if let Some(name) = &cfg.serde.details {
+        let details = err
+            .resp_details()
+            .and_then(|details| to_value(&*details).ok());
+        extensions.set(name, details.unwrap_or_default());
+    }
+    if let Some(name) = &cfg.serde.request_id {
+        let request_id = context.as_ref().map(|ctx| ctx.request_id().to_owned());
+        extensions.set(name, to_value(request_id).unwrap_or_default());
+    }
+    if let Some(name) = &cfg.serde.trace_id {
+        let trace_id = context.as_ref().and_then(RequestContext::trace_id);
+        extensions.set(name, to_value(trace_id).unwrap_or_default());
+    }
+
+    let mut error = async_graphql::Error::new(localized_message(&cfg.serde, err, context.as_ref()));
+    error.extensions = Some(extensions);
+    error
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use async_graphql::{value, EmptyMutation, EmptySubscription, Object, Schema};
+    use http::StatusCode;
+
+    use crate::{
+        config::InnerConfig, ConfigTrait, ErrorDetail, RespConfig, RespError, RespResult,
+        SerdeConfig,
+    };
+
+    use super::graphql_error_with_config;
+
+    struct MockErr;
+
+    impl RespError for MockErr {
+        fn log_message(&self) -> Cow<'_, str> {
+            "Mock Error".into()
+        }
+
+        fn resp_message(&self) -> Cow<'_, str> {
+            "Mock".into()
+        }
+
+        fn http_code(&self) -> StatusCode {
+            StatusCode::NOT_FOUND
+        }
+
+        fn resp_details(&self) -> Option<Cow<'_, [ErrorDetail]>> {
+            Some(Cow::Owned(vec![ErrorDetail::new(
+                "missing",
+                "user missing",
+            )]))
+        }
+
+        #[cfg(feature = "extra-error")]
+        type ExtraMessage = u32;
+
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Self::ExtraMessage {
+            1001
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn ok(&self) -> RespResult<i32, MockErr> {
+            RespResult::Success(12)
+        }
+
+        async fn err(&self) -> RespResult<i32, MockErr> {
+            RespResult::Err(MockErr)
+        }
+    }
+
+    struct FieldsConfig;
+
+    impl SerdeConfig for FieldsConfig {
+        fn details_name(&self) -> Option<Cow<'static, str>> {
+            Some("details".into())
+        }
+
+        fn request_id_name(&self) -> Option<Cow<'static, str>> {
+            Some("request-id".into())
+        }
+    }
+
+    impl RespConfig for FieldsConfig {}
+
+    impl ConfigTrait for FieldsConfig {}
+
+    #[tokio::test]
+    async fn test_resolve() {
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+        let resp = schema.execute("{ ok }").await;
+        assert!(resp.errors.is_empty());
+        assert_eq!(resp.data, value!({ "ok": 12 }));
+
+        let resp = schema.execute("{ err }").await;
+        let err = &resp.errors[0];
+        assert_eq!(err.message, "Mock");
+        let extensions = err.extensions.as_ref().unwrap();
+        assert_eq!(extensions.get("status"), Some(&value!(404)));
+        #[cfg(feature = "extra-error")]
+        assert_eq!(extensions.get("extra-error-message"), Some(&value!(1001)));
+    }
+
+    #[tokio::test]
+    async fn test_configured_fields() {
+        let cfg = InnerConfig::from_cfg(&FieldsConfig);
+        #[cfg(feature = "context")]
+        let err = crate::RequestContext::new("abc")
+            .scope(async { graphql_error_with_config(&MockErr, &cfg) })
+            .await;
+        #[cfg(not(feature = "context"))]
+        let err = graphql_error_with_config(&MockErr, &cfg);
+        let extensions = err.extensions.unwrap();
+        #[cfg(feature = "context")]
+        assert_eq!(extensions.get("request-id"), Some(&value!("abc")));
+        assert_eq!(
+            extensions.get("details"),
+            Some(&value!([{ "code": "missing", "message": "user missing" }]))
+        );
+    }
+}
@@ -1,22 +1,32 @@
+#[cfg(feature = "actix-web")]
+pub(crate) mod actix;
+#[cfg(feature = "axum")]
 pub mod axum;
+#[cfg(feature = "async-graphql")]
+pub(crate) mod graphql;
+#[cfg(feature = "poem")]
+pub(crate) mod poem;
+#[cfg(feature = "salvo")]
+mod salvo;
+#[cfg(feature = "tonic")]
+pub(crate) mod tonic;
 
 #[allow(unused_imports)]
 use std::str::FromStr;
 
 use super::{serde::SerializeWrap, RespResult};
 use crate::{
+    config::InnerRespConfig,
     expect_ext::ExpectExt,
     extra_flag::effect::{BodyEffect, Effects},
     get_config,
     resp_body::RespBody,
     resp_error::RespError,
+    ExtraFlag, ExtraFlags,
 };
 use http::{header::CONTENT_TYPE, HeaderMap, HeaderValue, StatusCode};
 #[cfg(feature = "tracing")]
-use {
-    trace as tracing,
-    trace::{event, instrument, Level},
-};
+use trace::{event, instrument, Level};
 
 #[allow(dead_code)]
 static JSON_TYPE: &mime::Mime = &mime::APPLICATION_JSON;
@@ -36,6 +46,14 @@ impl PrepareRespond {
         instrument(fields(this = "PrepareRespond"), skip_all)
     )]
     pub fn from_resp_result<T, E>(resp: &RespResult<T, E>) -> Self
+    where
+        T: RespBody,
+        E: RespError,
+    {
+        Self::with_config(resp, &get_config().resp)
+    }
+
+    fn with_config<T, E>(resp: &RespResult<T, E>, cfg: &InnerRespConfig) -> Self
     where
         T: RespBody,
         E: RespError,
@@ -46,18 +64,21 @@ impl PrepareRespond {
             headers: HeaderMap::new(),
         };
 
-        #[allow(unused_variables)]
-        let cfg = &get_config().resp;
+        // the unit-like success as if it has the `NoContent` flag
+        let no_content = (cfg.unit_no_content && is_unit_success(resp))
+            .then(|| ExtraFlags::from(ExtraFlag::NoContent));
+
         #[cfg(feature = "tracing")]
         event!(Level::DEBUG, prepare.state = "Set Payload");
-        this.serde_body(resp);
+        this.serde_body(resp, no_content.as_ref());
         #[cfg(feature = "tracing")]
         event!(Level::DEBUG, prepare.state = "Set Status");
-        this.set_status(resp);
+        this.set_status(resp, no_content.as_ref());
         #[cfg(feature = "tracing")]
         event!(Level::DEBUG, prepare.state = "Set Headers");
         this.set_header(
             resp,
+            no_content.as_ref(),
             #[cfg(feature = "extra-error")]
             cfg.extra_code.as_ref(),
         );
@@ -73,12 +94,16 @@ impl PrepareRespond {
     }
 
     #[allow(clippy::map_identity)]
-    fn serde_body<T, E>(&mut self, resp: &RespResult<T, E>)
+    fn serde_body<T, E>(&mut self, resp: &RespResult<T, E>, no_content: Option<&ExtraFlags>)
     where
         T: RespBody,
         E: RespError,
     {
-        if let BodyEffect::Continue = resp.body_effect(&mut self.body) {
+        let effect = match no_content {
+            Some(flags) => flags.body_effect(&mut self.body),
+            None => resp.body_effect(&mut self.body),
+        };
+        if let BodyEffect::Continue = effect {
             #[cfg(feature = "tracing")]
             event!(Level::DEBUG, body.body_effect = "Continue");
             serde_json::to_writer(&mut self.body, &SerializeWrap(resp))
@@ -92,6 +117,7 @@ impl PrepareRespond {
     fn set_header<T, E>(
         &mut self,
         resp: &RespResult<T, E>,
+        no_content: Option<&ExtraFlags>,
         #[cfg(feature = "extra-error")] extra_header: Option<&http::header::HeaderName>,
     ) where
         T: RespBody,
@@ -122,17 +148,23 @@ impl PrepareRespond {
         }
         #[cfg(feature = "tracing")]
         event!(Level::DEBUG, "Apply Header Effect");
+        if let Some(flags) = no_content {
+            flags.headers_effect(&mut self.headers);
+        }
         resp.headers_effect(&mut self.headers);
     }
 
-    fn set_status<T, E>(&mut self, resp: &RespResult<T, E>)
+    fn set_status<T, E>(&mut self, resp: &RespResult<T, E>, no_content: Option<&ExtraFlags>)
     where
         T: RespBody,
         E: RespError,
     {
         // status code
         let status = match resp {
-            RespResult::Success(_) => StatusCode::OK,
+            RespResult::Success(body) => no_content
+                .and_then(Effects::status_effect)
+                .or_else(|| body.success_status())
+                .unwrap_or(StatusCode::OK),
             RespResult::Err(ref e) => {
                 #[cfg(feature = "tracing")]
                 event!(
@@ -151,6 +183,35 @@ impl PrepareRespond {
     }
 }
 
+impl<T, E> RespResult<T, E>
+where
+    T: RespBody,
+    E: RespError,
+{
+    /// convert into the framework-neutral [`http::Response`],
+    /// applying the config, flags and effects just like the framework integrations
+    ///
+    /// it can be used in the hyper services, lambda handlers or any other framework
+    pub fn into_http_response(self) -> http::Response<Vec<u8>> {
+        PrepareRespond::from_resp_result(&self).into_http()
+    }
+}
+
+impl PrepareRespond {
+    fn into_http(self) -> http::Response<Vec<u8>> {
+        let mut response = http::Response::new(self.body);
+        *response.status_mut() = self.status;
+        *response.headers_mut() = self.headers;
+        response
+    }
+}
+
+/// whether the `resp` is a success with unit-like body, [`Nil`] or `()`,
+/// and the body not declaring its own status
+fn is_unit_success<T: RespBody, E>(resp: &RespResult<T, E>) -> bool {
+    matches!(resp, RespResult::Success(body) if T::is_unit() && body.success_status().is_none())
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct Nil;
 
@@ -176,7 +237,10 @@ impl std::fmt::Display for Nil {
 mod test {
     use http::StatusCode;
 
-    use crate::{ExtraFlag, RespError, RespResult};
+    use crate::{
+        config::InnerRespConfig, extra_flag::effect::Effects, ExtraFlag, LoadSerde, Nil, RespBody,
+        RespConfig, RespError, RespResult,
+    };
 
     use super::PrepareRespond;
     struct MockErr;
@@ -208,4 +272,89 @@ mod test {
         assert_eq!(p.headers.len(), 1);
         println!("{p:#?}")
     }
+
+    struct UnitConfig;
+
+    impl RespConfig for UnitConfig {
+        fn unit_no_content(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_unit_no_content() {
+        let cfg = InnerRespConfig::into_inner(&UnitConfig);
+
+        let p = PrepareRespond::with_config(&RespResult::<_, MockErr>::Success(Nil), &cfg);
+        assert_eq!(p.status, StatusCode::NO_CONTENT);
+        assert!(p.body.is_empty());
+        assert!(p.headers.is_empty());
+
+        let p = PrepareRespond::with_config(
+            &RespResult::<_, MockErr>::Success(()).with_flags(()),
+            &cfg,
+        );
+        assert_eq!(p.status, StatusCode::NO_CONTENT);
+
+        let p = PrepareRespond::with_config(&RespResult::<_, MockErr>::Success(1), &cfg);
+        assert_eq!(p.status, StatusCode::OK);
+        assert!(!p.body.is_empty());
+
+        let p = PrepareRespond::with_config(&RespResult::<Nil, _>::Err(MockErr), &cfg);
+        assert_eq!(p.status, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    struct Borrowed<'a>(&'a str);
+
+    impl<'a> LoadSerde for Borrowed<'a> {
+        type SerdeData = &'a str;
+
+        fn load_serde(&self) -> &Self::SerdeData {
+            &self.0
+        }
+    }
+
+    impl Effects for Borrowed<'_> {}
+
+    impl RespBody for Borrowed<'_> {}
+
+    #[test]
+    fn test_borrowed_body() {
+        let cfg = InnerRespConfig::into_inner(&UnitConfig);
+        let name = String::from("borrowed");
+
+        let p =
+            PrepareRespond::with_config(&RespResult::<_, MockErr>::Success(Borrowed(&name)), &cfg);
+        assert_eq!(p.status, StatusCode::OK);
+        assert!(std::str::from_utf8(&p.body).unwrap().contains("borrowed"));
+    }
+
+    #[test]
+    fn test_into_http_response() {
+        let resp = RespResult::<_, MockErr>::Success(12i32)
+            .with_flags(ExtraFlag::insert_header(http::header::ETAG, "1234567890"))
+            .into_http_response();
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers()[http::header::ETAG], "1234567890");
+        assert_eq!(
+            resp.headers()[http::header::CONTENT_TYPE],
+            mime::APPLICATION_JSON.as_ref()
+        );
+        assert!(!resp.body().is_empty());
+
+        let resp = RespResult::<i32, _>::Err(MockErr).into_http_response();
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn test_no_content_flag() {
+        let a = RespResult::<_, MockErr>::Success(12i32).with_flags(ExtraFlag::no_content());
+
+        let p = PrepareRespond::from_resp_result(&a);
+
+        assert_eq!(p.status, StatusCode::NO_CONTENT);
+        assert!(p.body.is_empty());
+        assert!(p.headers.is_empty());
+    }
 }
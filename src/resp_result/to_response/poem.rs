@@ -0,0 +1,80 @@
+use crate::{resp_body::RespBody, RespError, RespResult};
+
+impl<T, E> poem::IntoResponse for RespResult<T, E>
+where
+    T: RespBody + Send,
+    E: RespError + Send,
+{
+    #[inline]
+    #[cfg_attr(
+        feature = "tracing",
+        trace::instrument(name = "poem-into-response", skip_all)
+    )]
+    fn into_response(self) -> poem::Response {
+        poem_response(self)
+    }
+}
+
+/// build the poem response of `resp`
+pub(crate) fn poem_response<T, E>(resp: RespResult<T, E>) -> poem::Response
+where
+    T: RespBody,
+    E: RespError,
+{
+    let (parts, body) = resp.into_http_response().into_parts();
+    let mut response = poem::Response::from(body);
+    response.set_status(parts.status);
+    *response.headers_mut() = parts.headers;
+    response
+}
+
+#[cfg(all(test, feature = "poem-test"))]
+mod test {
+    use http::StatusCode;
+    use poem::{handler, test::TestClient, Route};
+
+    use crate::{ExtraFlag, FlagWrap, RespError, RespResult};
+
+    struct MockErr;
+
+    impl RespError for MockErr {
+        fn log_message(&self) -> std::borrow::Cow<'_, str> {
+            "Mock Error".into()
+        }
+
+        fn http_code(&self) -> StatusCode {
+            StatusCode::BAD_REQUEST
+        }
+
+        #[cfg(feature = "extra-error")]
+        type ExtraMessage = String;
+
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Self::ExtraMessage {
+            "Mock".into()
+        }
+    }
+
+    #[handler]
+    fn success() -> RespResult<FlagWrap<i32>, MockErr> {
+        RespResult::Success(12).with_flags(ExtraFlag::insert_header("x-foo", "bar"))
+    }
+
+    #[handler]
+    fn failure() -> RespResult<i32, MockErr> {
+        RespResult::Err(MockErr)
+    }
+
+    #[tokio::test]
+    async fn test_into_response() {
+        let cli = TestClient::new(Route::new().at("/ok", success).at("/err", failure));
+
+        let resp = cli.get("/ok").send().await;
+        resp.assert_status_is_ok();
+        resp.assert_header("x-foo", "bar");
+        resp.assert_content_type("application/json");
+
+        let resp = cli.get("/err").send().await;
+        resp.assert_status(StatusCode::BAD_REQUEST);
+    }
+}
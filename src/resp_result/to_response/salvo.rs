@@ -0,0 +1,84 @@
+use salvo::{Response, Scribe};
+
+use crate::{resp_body::RespBody, RespError, RespResult};
+
+impl<T, E> Scribe for RespResult<T, E>
+where
+    T: RespBody,
+    E: RespError,
+{
+    #[inline]
+    #[cfg_attr(
+        feature = "tracing",
+        trace::instrument(name = "salvo-render", skip_all)
+    )]
+    fn render(self, res: &mut Response) {
+        let (parts, body) = self.into_http_response().into_parts();
+        res.status_code(parts.status);
+        res.headers_mut().extend(parts.headers);
+        res.body(body);
+    }
+}
+
+#[cfg(all(test, feature = "salvo-test"))]
+mod test {
+    use http::StatusCode;
+    use salvo::{
+        handler,
+        test::{ResponseExt, TestClient},
+        Router, Service,
+    };
+
+    use crate::{ExtraFlag, FlagWrap, RespError, RespResult};
+
+    struct MockErr;
+
+    impl RespError for MockErr {
+        fn log_message(&self) -> std::borrow::Cow<'_, str> {
+            "Mock Error".into()
+        }
+
+        fn http_code(&self) -> StatusCode {
+            StatusCode::BAD_REQUEST
+        }
+
+        #[cfg(feature = "extra-error")]
+        type ExtraMessage = String;
+
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Self::ExtraMessage {
+            "Mock".into()
+        }
+    }
+
+    #[handler]
+    async fn success() -> RespResult<FlagWrap<i32>, MockErr> {
+        RespResult::Success(12).with_flags(ExtraFlag::insert_header("x-foo", "bar"))
+    }
+
+    #[handler]
+    async fn failure() -> RespResult<i32, MockErr> {
+        RespResult::Err(MockErr)
+    }
+
+    #[tokio::test]
+    async fn test_render() {
+        let service = Service::new(
+            Router::new()
+                .push(Router::with_path("ok").get(success))
+                .push(Router::with_path("err").get(failure)),
+        );
+
+        let mut resp = TestClient::get("http://127.0.0.1/ok").send(&service).await;
+        assert_eq!(resp.status_code, Some(StatusCode::OK));
+        assert_eq!(resp.headers().get("x-foo").unwrap(), "bar");
+        assert_eq!(
+            resp.headers().get("content-type").unwrap(),
+            "application/json"
+        );
+        assert!(resp.take_string().await.unwrap().starts_with('{'));
+
+        let resp = TestClient::get("http://127.0.0.1/err").send(&service).await;
+        assert_eq!(resp.status_code, Some(StatusCode::BAD_REQUEST));
+    }
+}
@@ -0,0 +1,213 @@
+use http::StatusCode;
+use tonic::{
+    metadata::{BinaryMetadataKey, BinaryMetadataValue, MetadataMap},
+    Code, Status,
+};
+#[cfg(feature = "tracing")]
+use trace::{event, Level};
+
+use crate::{
+    config::InnerConfig, context::RequestContext, get_config, i18n::localized_message, RespError,
+    RespResult,
+};
+
+/// the closest gRPC [`Code`] of the http `status`,
+/// it is the default of [`RespError::grpc_code`]
+///
+/// | http status                      | gRPC code            |
+/// |----------------------------------|----------------------|
+/// | `400`, `422` and other `4xx`     | `InvalidArgument`    |
+/// | `401`                            | `Unauthenticated`    |
+/// | `403`                            | `PermissionDenied`   |
+/// | `404`                            | `NotFound`           |
+/// | `405`, `501`                     | `Unimplemented`      |
+/// | `408`, `504`                     | `DeadlineExceeded`   |
+/// | `409`                            | `AlreadyExists`      |
+/// | `412`                            | `FailedPrecondition` |
+/// | `413`, `429`                     | `ResourceExhausted`  |
+/// | `416`                            | `OutOfRange`         |
+/// | `499`                            | `Cancelled`          |
+/// | `502`, `503`                     | `Unavailable`        |
+/// | other `5xx`                      | `Internal`           |
+/// | others                           | `Unknown`            |
+pub fn grpc_code_from_http(status: StatusCode) -> Code {
+    match status.as_u16() {
+        401 => Code::Unauthenticated,
+        403 => Code::PermissionDenied,
+        404 => Code::NotFound,
+        405 | 501 => Code::Unimplemented,
+        408 | 504 => Code::DeadlineExceeded,
+        409 => Code::AlreadyExists,
+        412 => Code::FailedPrecondition,
+        413 | 429 => Code::ResourceExhausted,
+        416 => Code::OutOfRange,
+        499 => Code::Cancelled,
+        502 | 503 => Code::Unavailable,
+        400..=499 => Code::InvalidArgument,
+        500..=599 => Code::Internal,
+        _ => Code::Unknown,
+    }
+}
+
+impl<T, E: RespError> RespResult<T, E> {
+    /// convert into the result of [tonic](https://docs.rs/tonic) service
+    ///
+    /// - `Success(_)` will be `Ok(_)` with the data
+    /// - `Err(_)` will be `Err(_)` with the [`Status`] having [`RespError::grpc_code`] as code,
+    ///   the response message (translated if possible) as message,
+    ///   the extra message in the metadata named by `RespConfig::head_extra_code` (feature `extra-error`)
+    ///   and the details in the binary metadata named by [`SerdeConfig::details_name`](crate::SerdeConfig::details_name)
+    ///   with suffix `-bin`, encoded in JSON
+    pub fn into_tonic(self) -> Result<tonic::Response<T>, Status> {
+        match self {
+            RespResult::Success(data) => Ok(tonic::Response::new(data)),
+            RespResult::Err(err) => Err(grpc_status(&err, get_config())),
+        }
+    }
+}
+
+impl<T, E: RespError> From<RespResult<T, E>> for Result<tonic::Response<T>, Status> {
+    #[inline]
+    fn from(resp: RespResult<T, E>) -> Self {
+        resp.into_tonic()
+    }
+}
+
+fn grpc_status<E: RespError>(err: &E, cfg: &InnerConfig) -> Status {
+    let code = err.grpc_code();
+    #[cfg(feature = "tracing")]
+    event!(
+        Level::WARN,
+        result = "RespResult::Err",
+        grpc_code = ?code,
+        error = %err.log_message()
+    );
+
+    let message = localized_message(&cfg.serde, err, RequestContext::current().as_ref());
+    let mut metadata = MetadataMap::new();
+
+    #[cfg(feature = "extra-error")]
+    if let Some(key) = &cfg.resp.extra_code {
+        let key = tonic::metadata::AsciiMetadataKey::from_bytes(key.as_str().as_bytes());
+        let value = err.extra_message().to_string().parse();
+        if let (Ok(key), Ok(value)) = (key, value) {
+            metadata.insert(key, value);
+        }
+    }
+
+    if let (Some(name), Some(details)) = (&cfg.serde.details, err.resp_details()) {
+        let key = BinaryMetadataKey::from_bytes(format!("{name}-bin").as_bytes());
+        let value = serde_json::to_vec(&*details);
+        if let (Ok(key), Ok(value)) = (key, value) {
+            metadata.insert_bin(key, BinaryMetadataValue::from_bytes(&value));
+        }
+    }
+
+    Status::with_metadata(code, message, metadata)
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use http::StatusCode;
+    use tonic::Code;
+
+    use crate::{
+        config::InnerConfig, ConfigTrait, ErrorDetail, RespConfig, RespError, RespResult,
+        SerdeConfig, ValidationErrors,
+    };
+
+    use super::{grpc_code_from_http, grpc_status};
+
+    struct MockErr;
+
+    impl RespError for MockErr {
+        fn log_message(&self) -> Cow<'_, str> {
+            "Mock Error".into()
+        }
+
+        fn resp_message(&self) -> Cow<'_, str> {
+            "Mock".into()
+        }
+
+        fn http_code(&self) -> StatusCode {
+            StatusCode::NOT_FOUND
+        }
+
+        fn resp_details(&self) -> Option<Cow<'_, [ErrorDetail]>> {
+            Some(Cow::Owned(vec![ErrorDetail::new(
+                "missing",
+                "user missing",
+            )]))
+        }
+
+        #[cfg(feature = "extra-error")]
+        type ExtraMessage = u32;
+
+        #[cfg(feature = "extra-error")]
+        fn extra_message(&self) -> Self::ExtraMessage {
+            1001
+        }
+    }
+
+    struct DetailsConfig;
+
+    impl SerdeConfig for DetailsConfig {
+        fn details_name(&self) -> Option<Cow<'static, str>> {
+            Some("error-details".into())
+        }
+    }
+
+    impl RespConfig for DetailsConfig {}
+
+    impl ConfigTrait for DetailsConfig {}
+
+    #[test]
+    fn test_grpc_code() {
+        assert_eq!(
+            grpc_code_from_http(StatusCode::BAD_REQUEST),
+            Code::InvalidArgument
+        );
+        assert_eq!(
+            grpc_code_from_http(StatusCode::UNPROCESSABLE_ENTITY),
+            Code::InvalidArgument
+        );
+        assert_eq!(grpc_code_from_http(StatusCode::NOT_FOUND), Code::NotFound);
+        assert_eq!(
+            grpc_code_from_http(StatusCode::TOO_MANY_REQUESTS),
+            Code::ResourceExhausted
+        );
+        assert_eq!(
+            grpc_code_from_http(StatusCode::SERVICE_UNAVAILABLE),
+            Code::Unavailable
+        );
+        assert_eq!(
+            grpc_code_from_http(StatusCode::INTERNAL_SERVER_ERROR),
+            Code::Internal
+        );
+        assert_eq!(ValidationErrors::new().grpc_code(), Code::InvalidArgument);
+    }
+
+    #[test]
+    fn test_into_tonic() {
+        let resp = RespResult::<_, MockErr>::Success(12).into_tonic().unwrap();
+        assert_eq!(resp.into_inner(), 12);
+
+        let status = RespResult::<i32, _>::Err(MockErr).into_tonic().unwrap_err();
+        assert_eq!(status.code(), Code::NotFound);
+        assert_eq!(status.message(), "Mock");
+        #[cfg(feature = "extra-error")]
+        assert_eq!(status.metadata().get("extra-error").unwrap(), "1001");
+    }
+
+    #[test]
+    fn test_details_metadata() {
+        let status = grpc_status(&MockErr, &InnerConfig::from_cfg(&DetailsConfig));
+        let details = status.metadata().get_bin("error-details-bin").unwrap();
+        assert_eq!(
+            details.to_bytes().unwrap().as_ref(),
+            br#"[{"code":"missing","message":"user missing"}]"#
+        );
+    }
+}
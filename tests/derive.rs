@@ -0,0 +1,88 @@
+// the derive macro `RespError` not support `extra-error` yet
+#![cfg(not(feature = "extra-error"))]
+
+use axum_resp_result::{RespError, StatusCode};
+
+#[derive(Debug, thiserror::Error, RespError)]
+enum UserError {
+    #[error("User {0} Not Found")]
+    #[resp_result(err_code = 404, msg_key = "user.not_found")]
+    NotFound(u64),
+    #[error("Bad Request")]
+    #[resp_result(err_code = 400, err_msg = "Invalid Request")]
+    BadRequest,
+    #[error("User {name} Conflict")]
+    #[resp_result(err_code = 409, msg_key = "user.conflict", args(id, name))]
+    Conflict {
+        id: u64,
+        name: String,
+        reason: String,
+    },
+    #[error("User {0} Banned until {1}")]
+    #[resp_result(err_code = 403, msg_key = "user.banned", args(1))]
+    Banned(u64, String),
+    #[error(transparent)]
+    #[resp_result(transparent)]
+    Inner(#[from] InnerError),
+}
+
+#[derive(Debug, thiserror::Error, RespError)]
+enum InnerError {
+    #[error("Locked")]
+    #[resp_result(err_code = "Locked", msg_key = "user.locked")]
+    Locked,
+}
+
+#[test]
+fn test_msg_key() {
+    let err = UserError::NotFound(1);
+    let key = err.resp_message_key().unwrap();
+    assert_eq!(key.key(), "user.not_found");
+    assert_eq!(key.args().count(), 0);
+    assert_eq!(err.http_code(), StatusCode::NOT_FOUND);
+
+    let err = UserError::Conflict {
+        id: 7,
+        name: "alice".into(),
+        reason: "duplicated".into(),
+    };
+    let key = err.resp_message_key().unwrap();
+    assert_eq!(key.key(), "user.conflict");
+    assert_eq!(
+        key.args().collect::<Vec<_>>(),
+        [("id", "7"), ("name", "alice")]
+    );
+
+    let err = UserError::Banned(7, "tomorrow".into());
+    let key = err.resp_message_key().unwrap();
+    assert_eq!(key.key(), "user.banned");
+    assert_eq!(key.args().collect::<Vec<_>>(), [("1", "tomorrow")]);
+
+    let err = UserError::BadRequest;
+    assert!(err.resp_message_key().is_none());
+    assert_eq!(err.resp_message(), "Invalid Request");
+
+    let err = UserError::from(InnerError::Locked);
+    assert_eq!(err.resp_message_key().unwrap().key(), "user.locked");
+    assert_eq!(err.http_code(), StatusCode::LOCKED);
+}
+
+#[cfg(feature = "tonic")]
+#[derive(Debug, thiserror::Error, RespError)]
+enum GrpcError {
+    #[error("Expired")]
+    #[resp_result(err_code = 401, grpc_code = "Unauthenticated")]
+    Expired,
+    #[error("Gone")]
+    #[resp_result(err_code = 404)]
+    Gone,
+}
+
+#[cfg(feature = "tonic")]
+#[test]
+fn test_grpc_code() {
+    use axum_resp_result::GrpcCode;
+
+    assert_eq!(GrpcError::Expired.grpc_code(), GrpcCode::Unauthenticated);
+    assert_eq!(GrpcError::Gone.grpc_code(), GrpcCode::NotFound);
+}
@@ -0,0 +1,277 @@
+// the derive macro `RespError` not support `extra-error` yet
+#![cfg(not(feature = "extra-error"))]
+
+use std::{marker::PhantomData, sync::Arc};
+
+use axum_resp_result::{resp_result, Nil, NotFound, RespError, RespResult};
+
+#[derive(Debug, PartialEq, thiserror::Error, RespError)]
+enum ItemError {
+    #[error("Item {0} Not Found")]
+    #[resp_result(err_code = 404)]
+    NotFound(u32),
+    #[error("Bad Item")]
+    #[resp_result(err_code = 400)]
+    Bad,
+    #[error("Missing")]
+    #[resp_result(err_code = 404)]
+    Missing,
+}
+
+impl From<NotFound> for ItemError {
+    fn from(_: NotFound) -> Self {
+        ItemError::Missing
+    }
+}
+
+fn load(id: u32) -> Result<u32, ItemError> {
+    if id < 10 {
+        Ok(id * 2)
+    } else {
+        Err(ItemError::NotFound(id))
+    }
+}
+
+fn check(id: u32) -> RespResult<u32, ItemError> {
+    if id % 2 == 1 {
+        RespResult::Success(id)
+    } else {
+        RespResult::Err(ItemError::Bad)
+    }
+}
+
+#[resp_result]
+fn plain(id: u32) -> RespResult<u32, ItemError> {
+    let value = load(id)?;
+    let checked = check(id)?;
+    RespResult::Success(value + checked)
+}
+
+#[resp_result]
+fn in_macro(id: u32) -> RespResult<Vec<u32>, ItemError> {
+    let mut values = vec![load(id)?, check(id)?];
+    values.extend(vec![check(1)?; load(1)? as usize]);
+    RespResult::Success(values)
+}
+
+#[resp_result]
+fn in_format(id: u32) -> RespResult<String, ItemError> {
+    RespResult::Success(format!("{}-{}", load(id)?, check(id)?))
+}
+
+#[resp_result]
+async fn in_async(id: u32) -> Result<u32, ItemError> {
+    Ok(load(id)? + 1)
+}
+
+#[resp_result(none)]
+fn find(id: u32) -> Result<Option<u32>, ItemError> {
+    Ok(Some(load(id)?).filter(|v| *v != 0))
+}
+
+#[resp_result(none = ItemError::NotFound(0))]
+fn find_or(id: u32) -> RespResult<Option<u32>, ItemError> {
+    RespResult::Success(Some(check(id)?).filter(|v| *v > 1))
+}
+
+#[resp_result(none)]
+fn find_option(id: u32) -> Option<u32> {
+    Some(id).filter(|v| *v != 0)
+}
+
+#[resp_result(none = NotFound)]
+fn find_option_or(id: u32) -> Option<u32> {
+    Some(id).filter(|v| *v != 0)
+}
+
+#[resp_result(none = ItemError::Missing)]
+fn find_option_missing(id: u32) -> Option<u32> {
+    Some(id).filter(|v| *v != 0)
+}
+
+#[resp_result(none = ItemError::NotFound(404))]
+async fn find_option_async(id: u32) -> Option<u32> {
+    Some(id).filter(|v| *v != 0)
+}
+
+#[resp_result(empty_body_on = Nil, header("X-Empty" = "maybe"))]
+fn touch(id: u32) -> Result<Nil, ItemError> {
+    load(id)?;
+    Ok(Nil)
+}
+
+#[resp_result(empty_body_on = Nil)]
+fn count(id: u32) -> Result<u32, ItemError> {
+    load(id)
+}
+
+fn unwrap_err<T: std::fmt::Debug>(res: RespResult<T, ItemError>) -> ItemError {
+    match res {
+        RespResult::Success(data) => panic!("unexpected success: {data:?}"),
+        RespResult::Err(err) => err,
+    }
+}
+
+#[test]
+fn test_try_on_result_and_resp_result() {
+    assert!(matches!(plain(3), RespResult::Success(9)));
+    assert_eq!(unwrap_err(plain(11)), ItemError::NotFound(11));
+    assert_eq!(unwrap_err(plain(4)), ItemError::Bad);
+}
+
+#[test]
+fn test_try_inside_macro() {
+    match in_macro(3) {
+        RespResult::Success(data) => assert_eq!(data, [6, 3, 1, 1]),
+        RespResult::Err(err) => panic!("unexpected error: {err}"),
+    }
+    match in_format(3) {
+        RespResult::Success(data) => assert_eq!(data, "6-3"),
+        RespResult::Err(err) => panic!("unexpected error: {err}"),
+    }
+    assert_eq!(unwrap_err(in_format(4)), ItemError::Bad);
+    assert_eq!(unwrap_err(in_macro(12)), ItemError::NotFound(12));
+    assert_eq!(unwrap_err(in_macro(2)), ItemError::Bad);
+}
+
+#[tokio::test]
+async fn test_try_in_async() {
+    assert!(matches!(in_async(5).await, RespResult::Success(11)));
+    assert_eq!(unwrap_err(in_async(20).await), ItemError::NotFound(20));
+}
+
+#[tokio::test]
+async fn test_none() {
+    assert!(matches!(find(2), RespResult::Success(4)));
+    assert_eq!(unwrap_err(find(0)), ItemError::Missing);
+    assert_eq!(unwrap_err(find(12)), ItemError::NotFound(12));
+
+    assert!(matches!(find_or(3), RespResult::Success(3)));
+    assert_eq!(unwrap_err(find_or(1)), ItemError::NotFound(0));
+    assert_eq!(unwrap_err(find_or(2)), ItemError::Bad);
+
+    assert!(matches!(find_option(1), RespResult::Success(1)));
+    assert!(matches!(find_option(0), RespResult::Err(NotFound)));
+    assert!(matches!(find_option_or(0), RespResult::Err(NotFound)));
+    assert!(matches!(find_option_missing(1), RespResult::Success(1)));
+    assert_eq!(unwrap_err(find_option_missing(0)), ItemError::Missing);
+    assert_eq!(
+        unwrap_err(find_option_async(0).await),
+        ItemError::NotFound(404)
+    );
+}
+
+#[test]
+fn test_empty_body_on() {
+    let resp = touch(1).into_http_response();
+    assert_eq!(resp.status(), http::StatusCode::OK);
+    assert_eq!(resp.headers()["x-empty"], "maybe");
+    assert!(resp.body().is_empty());
+
+    let resp = count(1).into_http_response();
+    assert!(!resp.body().is_empty());
+
+    let resp = touch(12).into_http_response();
+    assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    assert!(!resp.body().is_empty());
+}
+
+struct Svc<T> {
+    base: u32,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Default + Into<u32>> Svc<T> {
+    fn new(base: u32) -> Self {
+        Self {
+            base,
+            _marker: PhantomData,
+        }
+    }
+
+    fn helper(id: u32) -> Result<u32, ItemError> {
+        load(id).map(|v| v + T::default().into())
+    }
+
+    #[resp_result]
+    fn create(id: u32) -> Result<u32, ItemError> {
+        Self::helper(id)
+    }
+
+    #[resp_result]
+    async fn create_async((id, extra): (u32, u32)) -> RespResult<u32, ItemError> {
+        let value = Self::helper(id)?;
+        RespResult::Success(value + extra)
+    }
+
+    #[resp_result]
+    fn by_ref(&self, id: u32) -> Result<u32, ItemError> {
+        Ok(load(id)? + self.base)
+    }
+
+    #[resp_result]
+    async fn by_arc(self: Arc<Self>, id: u32) -> Result<u32, ItemError> {
+        Ok(Self::helper(id)? + self.base)
+    }
+
+    #[resp_result]
+    fn by_value(self, id: u32) -> Result<u32, ItemError> {
+        Ok(load(id)? + self.base)
+    }
+}
+
+#[resp_result]
+impl<T: Default + Into<u32>> Svc<T> {
+    fn find(&self, id: u32) -> Result<u32, ItemError> {
+        Ok(load(id)? + self.base)
+    }
+
+    fn find_static(id: u32) -> RespResult<u32, ItemError> {
+        RespResult::Success(Self::helper(id)?)
+    }
+
+    async fn find_arc(self: Arc<Self>, id: u32) -> Result<u32, ItemError> {
+        Ok(load(id)? + self.base)
+    }
+
+    // the sibling method of this one must be gated by the `cfg` too
+    #[cfg(feature = "tonic")]
+    fn grpc(&self) -> Result<axum_resp_result::GrpcCode, ItemError> {
+        Ok(axum_resp_result::GrpcCode::Ok)
+    }
+}
+
+#[tokio::test]
+async fn test_receivers() {
+    assert!(matches!(Svc::<u8>::create(1), RespResult::Success(2)));
+    assert_eq!(unwrap_err(Svc::<u8>::create(11)), ItemError::NotFound(11));
+    assert!(matches!(
+        Svc::<u8>::create_async((1, 3)).await,
+        RespResult::Success(5)
+    ));
+
+    let svc = Svc::<u8>::new(10);
+    assert!(matches!(svc.by_ref(1), RespResult::Success(12)));
+    assert!(matches!(
+        Arc::new(Svc::<u8>::new(10)).by_arc(2).await,
+        RespResult::Success(14)
+    ));
+    assert!(matches!(svc.by_value(3), RespResult::Success(16)));
+}
+
+#[tokio::test]
+async fn test_impl_block() {
+    let svc = Svc::<u8>::new(10);
+    assert!(matches!(svc.find(1), RespResult::Success(12)));
+    assert_eq!(unwrap_err(svc.find(12)), ItemError::NotFound(12));
+    assert!(matches!(Svc::<u8>::find_static(2), RespResult::Success(4)));
+    assert!(matches!(
+        Arc::new(Svc::<u8>::new(1)).find_arc(3).await,
+        RespResult::Success(7)
+    ));
+    #[cfg(feature = "tonic")]
+    assert!(matches!(
+        svc.grpc(),
+        RespResult::Success(axum_resp_result::GrpcCode::Ok)
+    ));
+}